@@ -46,6 +46,36 @@
 /// # assert!(matches!(Fruit::from_index(3), None));
 /// # assert_eq!(Fruit::LENGTH, 3);
 /// ```
+///
+/// # Type aliases
+///
+/// Following the enum, the macro can optionally generate type aliases for an [`EnumMap`](crate::EnumMap)
+/// and an [`EnumSet`](crate::EnumSet) keyed by the enum,
+/// which removes the need to repeat the const generic length.
+///
+/// ```
+/// enumap::enumap! {
+///     #[derive(Debug, PartialEq)]
+///     pub enum Fruit {
+///         Orange,
+///         Banana,
+///         Grape,
+///     }
+///
+///     /// Map of fruits.
+///     pub type FruitMap<V> = EnumMap;
+///     pub type FruitSet = EnumSet;
+/// }
+///
+/// let mut stock = FruitMap::new();
+/// stock.insert(Fruit::Banana, 200);
+///
+/// let mut orders = FruitSet::new();
+/// orders.insert(Fruit::Grape);
+///
+/// let _: enumap::EnumMap<3, Fruit, i32> = stock;
+/// let _: enumap::EnumSet<3, Fruit> = orders;
+/// ```
 #[macro_export]
 macro_rules! enumap {
     (
@@ -56,6 +86,8 @@ macro_rules! enumap {
                 $v:ident
             ),* $(,)?
         }
+
+        $($aliases:tt)*
     ) =>{
         $(#[$($attr)*])*
         #[derive(Copy, Clone)]
@@ -80,6 +112,164 @@ macro_rules! enumap {
                 value as usize
             }
         }
+
+        $crate::__enumap_aliases! {
+            { 0 $(+ $crate::__replace_expr!($v 1))* } $name;
+            $($aliases)*
+        }
+    };
+}
+
+/// Macro to implement [`Enum`](crate::Enum) for a newtype over a dense integer range.
+///
+/// The macro generates the newtype, which automatically derives `Copy` and `Clone`,
+/// a validating constructor `new` and an accessor `get` for the wrapped integer.
+/// The bounds of the range must be literals, the range must not be empty and
+/// its length must fit into a `usize`.
+///
+/// # Example:
+///
+/// ```
+/// use enumap::{Enum, EnumMap};
+///
+/// enumap::enum_range! {
+///     /// A byte value.
+///     #[derive(Debug, PartialEq)]
+///     pub struct ByteVal(u8) in 0..=255
+/// }
+///
+/// let zero = ByteVal::new(0).unwrap();
+/// let max = ByteVal::new(255).unwrap();
+///
+/// let mut map = EnumMap::<{ ByteVal::LENGTH }, ByteVal, &str>::new();
+/// map.insert(zero, "zero");
+/// map.insert(max, "max");
+///
+/// assert_eq!(ByteVal::LENGTH, 256);
+/// assert_eq!(map[zero], "zero");
+/// assert_eq!(map.keys().last().map(ByteVal::get), Some(255));
+///
+/// enumap::enum_range! {
+///     struct Digit(u8) in 1..=9
+/// }
+///
+/// assert!(Digit::new(0).is_none());
+/// assert_eq!(Digit::to_index(Digit::new(1).unwrap()), 0);
+/// assert_eq!(Digit::from_index(8).map(Digit::get), Some(9));
+/// assert!(Digit::from_index(9).is_none());
+/// # assert!(Digit::verify().is_ok());
+/// # assert!(ByteVal::verify().is_ok());
+/// #
+/// # enumap::enum_range! { struct Signed(i8) in -128..=127 }
+/// # assert_eq!(Signed::LENGTH, 256);
+/// # assert_eq!(Signed::to_index(Signed::new(-128).unwrap()), 0);
+/// # assert_eq!(Signed::from_index(255).map(Signed::get), Some(127));
+/// # assert!(Signed::verify().is_ok());
+/// #
+/// # enumap::enum_range! { struct Offset(i64) in -3..=2 }
+/// # assert_eq!(Offset::LENGTH, 6);
+/// # assert_eq!(Offset::from_index(4).map(Offset::get), Some(1));
+/// # assert!(Offset::verify().is_ok());
+/// ```
+///
+/// Empty ranges are rejected at compile time:
+///
+/// ```compile_fail
+/// enumap::enum_range! {
+///     struct Empty(u8) in 5..=4
+/// }
+/// ```
+///
+/// As are ranges which do not fit into a `usize`:
+///
+/// ```compile_fail
+/// enumap::enum_range! {
+///     struct Huge(u128) in 0..=340282366920938463463374607431768211455
+/// }
+/// ```
+#[macro_export]
+macro_rules! enum_range {
+    (
+        $(#[$($attr:tt)*])*
+        $vis:vis struct $name:ident($ty:ty) in $start:literal..=$end:literal
+    ) => {
+        $(#[$($attr)*])*
+        #[derive(Copy, Clone)]
+        $vis struct $name($ty);
+
+        impl $name {
+            const START: $ty = $start;
+            const END: $ty = $end;
+
+            /// Number of values in the range, checked to be non-empty and to fit into a `usize`.
+            const RANGE_LENGTH: usize = {
+                assert!(Self::START <= Self::END, "`enum_range!` range must not be empty");
+                // Sign extending both bounds to `u128` keeps the difference exact
+                // for signed types as well, `START <= END` guarantees it does not wrap.
+                let diff = (Self::END as u128).wrapping_sub(Self::START as u128);
+                assert!(
+                    diff < usize::MAX as u128,
+                    "`enum_range!` range length does not fit into a `usize`"
+                );
+                diff as usize + 1
+            };
+
+            /// Creates a new value, returns `None` if the value is out of range.
+            $vis const fn new(value: $ty) -> Option<Self> {
+                if Self::START <= value && value <= Self::END {
+                    Some(Self(value))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the wrapped value.
+            $vis const fn get(self) -> $ty {
+                self.0
+            }
+        }
+
+        impl $crate::Enum<{ $name::RANGE_LENGTH }> for $name {
+            fn from_index(index: usize) -> Option<Self> {
+                if index < Self::RANGE_LENGTH {
+                    Some(Self((Self::START as u128).wrapping_add(index as u128) as $ty))
+                } else {
+                    None
+                }
+            }
+
+            fn to_index(value: Self) -> usize {
+                (value.0 as u128).wrapping_sub(Self::START as u128) as usize
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __enumap_aliases {
+    ($len:tt $name:ident;) => {};
+    (
+        $len:tt $name:ident;
+        $(#[$($attr:tt)*])*
+        $vis:vis type $alias:ident<$value:ident> = EnumMap;
+        $($rest:tt)*
+    ) => {
+        $(#[$($attr)*])*
+        $vis type $alias<$value> = $crate::EnumMap<$len, $name, $value>;
+
+        $crate::__enumap_aliases! { $len $name; $($rest)* }
+    };
+    (
+        $len:tt $name:ident;
+        $(#[$($attr:tt)*])*
+        $vis:vis type $alias:ident = EnumSet;
+        $($rest:tt)*
+    ) => {
+        $(#[$($attr)*])*
+        $vis type $alias = $crate::EnumSet<$len, $name>;
+
+        $crate::__enumap_aliases! { $len $name; $($rest)* }
     };
 }
 
@@ -116,6 +116,8 @@
 //! assert_eq!(map.get(five), None);
 //! ```
 //!
+//! For dense integer ranges like this the [`enum_range`] macro generates the implementation.
+//!
 //! Of course this is also possible for enums with attached data:
 //!
 //! ```
@@ -157,9 +159,18 @@
 //! ```
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod enum_macro;
+#[cfg(feature = "proptest")]
+mod proptest;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rkyv")]
+pub mod rkyv;
 #[cfg(feature = "serde")]
-mod serde;
+pub mod serde;
 
 pub mod map;
 pub mod set;
@@ -188,4 +199,153 @@ pub trait Enum<const LENGTH: usize>: Copy + Sized {
     ///
     /// Returned index must be in range `0..LENGTH`.
     fn to_index(value: Self) -> usize;
+
+    /// Returns the variant with the lowest index, `None` if the enum has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::Enum;
+    ///
+    /// assert_eq!(Fruit::first(), Some(Fruit::Orange));
+    /// ```
+    fn first() -> Option<Self> {
+        Self::from_index(0)
+    }
+
+    /// Returns the variant with the highest index, `None` if the enum has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::Enum;
+    ///
+    /// assert_eq!(Fruit::last(), Some(Fruit::Grape));
+    /// ```
+    fn last() -> Option<Self> {
+        Self::from_index(LENGTH.checked_sub(1)?)
+    }
+
+    /// Verifies that [`Enum::from_index`] and [`Enum::to_index`] are consistent
+    /// for all indices in `0..LENGTH`.
+    ///
+    /// This is the same check [`EnumMap::new`] performs with `debug_assertions` enabled,
+    /// but it runs regardless of the build profile. Useful to unit test hand written implementations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::Enum;
+    ///
+    /// assert!(Fruit::verify().is_ok());
+    ///
+    /// #[derive(Copy, Clone)]
+    /// struct Broken(usize);
+    ///
+    /// impl Enum<3> for Broken {
+    ///     fn from_index(index: usize) -> Option<Self> {
+    ///         (index < 4).then_some(Self(index))
+    ///     }
+    ///
+    ///     fn to_index(value: Self) -> usize {
+    ///         value.0
+    ///     }
+    /// }
+    ///
+    /// let err = Broken::verify().unwrap_err();
+    /// assert_eq!(err.index(), 3);
+    /// assert_eq!(err.reason(), "`from_index` yielded more variants than `LENGTH`");
+    /// ```
+    fn verify() -> Result<(), EnumImplError> {
+        verify_enum_impl::<LENGTH, Self>()
+    }
+
+    /// Returns a uniformly sampled random variant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum has no variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::Enum;
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let fruit = Fruit::random_variant(&mut rng);
+    ///
+    /// assert!(Fruit::to_index(fruit) < Fruit::LENGTH);
+    /// ```
+    #[cfg(feature = "rand")]
+    fn random_variant<R: ::rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        rand::random_variant::<LENGTH, Self, R>(rng)
+    }
+}
+
+/// Error returned when an [`Enum`] implementation is found to be inconsistent.
+///
+/// See [`Enum::verify`] and [`EnumMap::try_new`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnumImplError {
+    index: usize,
+    reason: &'static str,
+}
+
+impl EnumImplError {
+    /// Returns the index at which the implementation is inconsistent.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a description of the inconsistency.
+    pub fn reason(&self) -> &'static str {
+        self.reason
+    }
+}
+
+impl core::fmt::Display for EnumImplError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "invalid `Enum` implementation at index {}: {}",
+            self.index, self.reason
+        )
+    }
+}
+
+/// Verifies that `from_index` and `to_index` form a bijection between the variants
+/// and the indices `0..LENGTH`.
+pub(crate) fn verify_enum_impl<const LENGTH: usize, E>() -> Result<(), EnumImplError>
+where
+    E: Enum<LENGTH>,
+{
+    for index in 0..LENGTH {
+        let Some(v) = E::from_index(index) else {
+            return Err(EnumImplError {
+                index,
+                reason: "`from_index` did not construct a variant",
+            });
+        };
+
+        if E::to_index(v) != index {
+            return Err(EnumImplError {
+                index,
+                reason: "`to_index` returned a different index for the variant constructed with `from_index`",
+            });
+        }
+    }
+
+    if E::from_index(LENGTH).is_some() {
+        return Err(EnumImplError {
+            index: LENGTH,
+            reason: "`from_index` yielded more variants than `LENGTH`",
+        });
+    }
+
+    Ok(())
 }
@@ -2,31 +2,78 @@
 
 use core::{fmt, marker::PhantomData};
 
-use crate::Enum;
+use crate::{Enum, EnumImplError, EnumSet};
 
 /// An enum map backed by an array.
 ///
 /// The map is backed by `[Option<V>; E::LENGTH]`, which means it does not allocate,
 /// but depending on the length of the enum and the size of the `V` it can require a significant
-/// amount of space. In some cases it may be beneficial to box the enum map.
+/// amount of space. In some cases it may be beneficial to box the enum map,
+/// with the `alloc` feature [`EnumMap::boxed_new`] allocates the map directly on the heap.
 ///
 /// To reduce the amount of space required, consider using values with a niche, like `NonZeroUsize`.
 ///
 /// An incorrectly implemented [`Enum`] trait will not cause undefined behaviour but
 /// may introduce random panics and incorrect results. Consider using the [`enumap`](crate::enumap)
 /// macro to implement [`Enum`] correctly.
+///
+/// The `LENGTH` of the map must match the length of the [`Enum`] implementation,
+/// a mismatch is rejected at compile time:
+///
+/// ```compile_fail
+/// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+/// use enumap::EnumMap;
+///
+/// // `Fruit` has 3 variants and only implements `Enum<3>`.
+/// let map = EnumMap::<2, Fruit, i32>::new();
+/// ```
+///
+/// This also applies to [`Enum`] implementations which override [`Enum::LENGTH`]
+/// with a value different from the const generic length. This check is performed
+/// when the constructor is instantiated and therefore is only reported by `cargo build`,
+/// not by `cargo check`:
+///
+/// ```compile_fail
+/// use enumap::{Enum, EnumMap};
+///
+/// #[derive(Copy, Clone)]
+/// struct Digit(u8);
+///
+/// impl Enum<10> for Digit {
+///     const LENGTH: usize = 9;
+///
+///     fn from_index(index: usize) -> Option<Self> {
+///         (index < 10).then_some(Self(index as u8))
+///     }
+///
+///     fn to_index(value: Self) -> usize {
+///         value.0 as usize
+///     }
+/// }
+///
+/// let map = EnumMap::<10, Digit, i32>::new();
+/// ```
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
 pub struct EnumMap<const LENGTH: usize, E: Enum<LENGTH>, V> {
     data: [Option<V>; LENGTH],
     _enum: PhantomData<E>,
 }
 
 impl<const LENGTH: usize, E: Enum<LENGTH>, V> EnumMap<LENGTH, E, V> {
+    /// Compile time assertion that the length of the map matches [`Enum::LENGTH`].
+    const ASSERT_LENGTH: () = assert!(
+        <E as Enum<LENGTH>>::LENGTH == LENGTH,
+        "`Enum::LENGTH` does not match the length of the `EnumMap`"
+    );
+
     /// Creates an empty `EnumMap`.
     ///
     /// With `debug_assertions` enabled, the constructor verifies the implementation
     /// of the [`Enum`] trait.
     pub fn new() -> Self {
+        let () = Self::ASSERT_LENGTH;
+
         #[cfg(debug_assertions)]
         assert_enum_impl::<LENGTH, E>();
 
@@ -36,13 +83,116 @@ impl<const LENGTH: usize, E: Enum<LENGTH>, V> EnumMap<LENGTH, E, V> {
         }
     }
 
-    /// Returns a slice of the underlying array.
+    /// Creates an empty `EnumMap` after verifying the implementation of the [`Enum`] trait.
+    ///
+    /// Unlike [`EnumMap::new`], the verification runs regardless of `debug_assertions`
+    /// and reports the first inconsistency instead of panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let map = EnumMap::<{ Fruit::LENGTH }, Fruit, i32>::try_new();
+    /// assert!(map.is_ok());
+    ///
+    /// #[derive(Copy, Clone, Debug)]
+    /// struct Broken(usize);
+    ///
+    /// impl Enum<3> for Broken {
+    ///     fn from_index(index: usize) -> Option<Self> {
+    ///         (index < 3).then_some(Self(index))
+    ///     }
+    ///
+    ///     fn to_index(value: Self) -> usize {
+    ///         value.0.min(1)
+    ///     }
+    /// }
+    ///
+    /// let err = EnumMap::<3, Broken, i32>::try_new().unwrap_err();
+    /// assert_eq!(err.index(), 2);
+    /// ```
+    pub fn try_new() -> Result<Self, EnumImplError> {
+        let () = Self::ASSERT_LENGTH;
+
+        crate::verify_enum_impl::<LENGTH, E>()?;
+
+        Ok(Self {
+            data: [(); LENGTH].map(|_| None),
+            _enum: PhantomData,
+        })
+    }
+
+    /// Creates an `EnumMap` from the underlying array representation in a const context.
+    pub(crate) const fn from_array(data: [Option<V>; LENGTH]) -> Self {
+        let () = Self::ASSERT_LENGTH;
+
+        Self {
+            data,
+            _enum: PhantomData,
+        }
+    }
+
+    /// Returns mutable references to the value of the key and the value of its successor,
+    /// i.e. the key with the next higher index.
+    ///
+    /// The second reference is `None` if the successor is absent or `key` is the last key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 2), (Fruit::Grape, 3)]);
+    ///
+    /// if let (Some(banana), Some(grape)) = map.adjacent_mut(Fruit::Banana) {
+    ///     *grape += *banana;
+    /// }
+    /// assert_eq!(map[Fruit::Grape], 5);
+    ///
+    /// assert_eq!(map.adjacent_mut(Fruit::Grape), (Some(&mut 5), None));
+    /// ```
+    pub fn adjacent_mut(&mut self, key: E) -> (Option<&mut V>, Option<&mut V>) {
+        match &mut self.data[E::to_index(key)..] {
+            [current, next, ..] => (current.as_mut(), next.as_mut()),
+            [current] => (current.as_mut(), None),
+            [] => (None, None),
+        }
+    }
+
+    /// Calls `f` on every present key-value pair, in index order.
+    ///
+    /// Values are mutated in place, absent entries are skipped.
     ///
     /// # Examples
     ///
     /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
     /// use enumap::EnumMap;
     ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    /// map.apply(|_, value| *value *= 2);
+    ///
+    /// assert_eq!(map, EnumMap::from([(Fruit::Orange, 2), (Fruit::Grape, 6)]));
+    /// ```
+    pub fn apply<F: FnMut(E, &mut V)>(&mut self, mut f: F) {
+        for (key, value) in self.iter_mut() {
+            f(key, value);
+        }
+    }
+
+    /// Returns a slice of the underlying array.
+    ///
+    /// The option at index `i` holds the value for the key `E::from_index(i)`,
+    /// which makes the slice a building block for custom bulk operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumap::{Enum, EnumMap};
+    ///
     /// enumap::enumap! {
     ///     #[derive(Debug)]
     ///     enum Fruit {
@@ -53,13 +203,19 @@ impl<const LENGTH: usize, E: Enum<LENGTH>, V> EnumMap<LENGTH, E, V> {
     ///
     /// let map = EnumMap::from([(Fruit::Banana, 5)]);
     /// assert_eq!(map.as_slice(), &[None, Some(5)]);
+    ///
+    /// let slice = map.as_slice();
+    /// assert_eq!(slice[Fruit::to_index(Fruit::Banana)], Some(5));
+    /// assert_eq!(slice[Fruit::to_index(Fruit::Orange)], None);
     /// ```
-    pub fn as_slice(&self) -> &[Option<V>; LENGTH] {
+    pub const fn as_slice(&self) -> &[Option<V>; LENGTH] {
         &self.data
     }
 
     /// Returns a mutable slice of the underlying array.
     ///
+    /// The option at index `i` holds the value for the key `E::from_index(i)`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -87,6 +243,88 @@ impl<const LENGTH: usize, E: Enum<LENGTH>, V> EnumMap<LENGTH, E, V> {
         &mut self.data
     }
 
+    /// Creates an `EnumMap` on the heap with a value for every key,
+    /// produced by calling `f` with the key.
+    ///
+    /// Like [`EnumMap::boxed_new`] the map is never placed on the stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let map: Box<EnumMap<{ Fruit::LENGTH }, Fruit, usize>> =
+    ///     EnumMap::boxed_from_fn(|fruit| Fruit::to_index(fruit) * 10);
+    ///
+    /// assert_eq!(map[Fruit::Orange], 0);
+    /// assert_eq!(map[Fruit::Grape], 20);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn boxed_from_fn<F: FnMut(E) -> V>(mut f: F) -> alloc::boxed::Box<Self> {
+        Self::boxed_with(|index| E::from_index(index).map(&mut f))
+    }
+
+    /// Creates an empty `EnumMap` on the heap.
+    ///
+    /// Unlike `Box::new(EnumMap::new())` the map is never placed on the stack,
+    /// which avoids large stack temporaries for enums with many variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let mut map: Box<EnumMap<{ Fruit::LENGTH }, Fruit, [u8; 1024]>> = EnumMap::boxed_new();
+    /// assert!(map.is_empty());
+    ///
+    /// map.insert(Fruit::Banana, [1; 1024]);
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn boxed_new() -> alloc::boxed::Box<Self> {
+        Self::boxed_with(|_| None)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn boxed_with<F>(f: F) -> alloc::boxed::Box<Self>
+    where
+        F: FnMut(usize) -> Option<V>,
+    {
+        use alloc::boxed::Box;
+
+        let () = Self::ASSERT_LENGTH;
+
+        #[cfg(debug_assertions)]
+        assert_enum_impl::<LENGTH, E>();
+
+        let data: Box<[Option<V>]> = (0..LENGTH).map(f).collect();
+        let data: Box<[Option<V>; LENGTH]> = match data.try_into() {
+            Ok(data) => data,
+            Err(_) => unreachable!("slice has exactly `LENGTH` elements"),
+        };
+
+        // SAFETY: `EnumMap` is `repr(transparent)` over `[Option<V>; LENGTH]`,
+        // the remaining field is a zero sized `PhantomData`.
+        unsafe { Box::from_raw(Box::into_raw(data).cast::<Self>()) }
+    }
+
+    /// Returns the number of entries the map can hold, which is always `LENGTH`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let map = EnumMap::<{ Fruit::LENGTH }, Fruit, i32>::new();
+    /// assert_eq!(map.capacity(), Fruit::LENGTH);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        LENGTH
+    }
+
     /// Clears the map, removing all key-value pairs.
     ///
     /// # Examples
@@ -124,25 +362,29 @@ impl<const LENGTH: usize, E: Enum<LENGTH>, V> EnumMap<LENGTH, E, V> {
         self.get(key).is_some()
     }
 
-    /// Returns a reference to the value for the corresponding key.
+    /// Returns `true` if any present value is equal to `value`.
     ///
     /// # Examples
     ///
     /// ```
-    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
     /// use enumap::EnumMap;
     ///
-    /// let mut map = EnumMap::new();
-    /// map.insert(Fruit::Orange, 3);
+    /// let map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
     ///
-    /// assert_eq!(map.get(Fruit::Orange), Some(&3));
-    /// assert_eq!(map.get(Fruit::Banana), None);
+    /// assert!(map.contains_value(&3));
+    /// assert!(!map.contains_value(&2));
     /// ```
-    pub fn get(&self, key: E) -> Option<&V> {
-        self.data[E::to_index(key)].as_ref()
+    pub fn contains_value(&self, value: &V) -> bool
+    where
+        V: PartialEq,
+    {
+        self.values().any(|v| v == value)
     }
 
-    /// Returns a mutable reference to the value for the corresponding key.
+    /// Returns the number of keys without a value in the map.
+    ///
+    /// This is equivalent to `LENGTH - map.count_present()`.
     ///
     /// # Examples
     ///
@@ -151,21 +393,17 @@ impl<const LENGTH: usize, E: Enum<LENGTH>, V> EnumMap<LENGTH, E, V> {
     /// use enumap::EnumMap;
     ///
     /// let mut map = EnumMap::new();
-    /// map.insert(Fruit::Orange, 3);
-    ///
-    /// if let Some(value) = map.get_mut(Fruit::Orange) {
-    ///     *value += 2;
-    /// }
-    /// assert_eq!(map[Fruit::Orange], 5);
+    /// assert_eq!(map.count_absent(), 3);
+    /// map.insert(Fruit::Orange, "a");
+    /// assert_eq!(map.count_absent(), 2);
     /// ```
-    pub fn get_mut(&mut self, key: E) -> Option<&mut V> {
-        self.data[E::to_index(key)].as_mut()
+    pub fn count_absent(&self) -> usize {
+        LENGTH - self.count_present()
     }
 
-    /// Inserts a key-value pair into the map.
+    /// Returns the number of keys with a value in the map.
     ///
-    /// If the map already had a value present for the key,
-    /// the old value is returned.
+    /// This is equivalent to [`EnumMap::len`].
     ///
     /// # Examples
     ///
@@ -174,368 +412,3231 @@ impl<const LENGTH: usize, E: Enum<LENGTH>, V> EnumMap<LENGTH, E, V> {
     /// use enumap::EnumMap;
     ///
     /// let mut map = EnumMap::new();
-    /// assert_eq!(map.insert(Fruit::Orange, 3), None);
-    /// assert_eq!(map.insert(Fruit::Orange, 5), Some(3));
+    /// assert_eq!(map.count_present(), 0);
+    /// map.insert(Fruit::Orange, "a");
+    /// assert_eq!(map.count_present(), 1);
     /// ```
-    pub fn insert(&mut self, key: E, value: V) -> Option<V> {
-        core::mem::replace(&mut self.data[E::to_index(key)], Some(value))
+    pub fn count_present(&self) -> usize {
+        self.len()
     }
 
-    /// Creates a consuming iterator visiting all the values in order.
-    /// The map cannot be used after calling this. The iterator element type is `V`.
+    /// Returns a wrapper which formats every key of the map, including absent ones.
+    ///
+    /// Present values are rendered as `Some(value)`, absent values as `None`.
     ///
     /// # Examples
     ///
     /// ```
-    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// # enumap::enumap! { #[derive(Debug)] enum Fruit { Orange, Banana, Grape, } }
     /// use enumap::EnumMap;
     ///
-    /// let mut map = EnumMap::from([
-    ///     (Fruit::Grape, 3),
-    ///     (Fruit::Banana, 2),
-    ///     (Fruit::Orange, 1),
-    /// ]);
+    /// let map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
     ///
-    /// let vec: Vec<i32> = map.into_values().collect();
-    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// assert_eq!(format!("{map:?}"), "{Orange: 1, Grape: 3}");
+    /// assert_eq!(
+    ///     format!("{:?}", map.debug_full()),
+    ///     "{Orange: Some(1), Banana: None, Grape: Some(3)}"
+    /// );
     /// ```
-    pub fn into_values(self) -> IntoValues<LENGTH, E, V> {
-        IntoValues {
-            inner: self.into_iter(),
-        }
+    pub fn debug_full(&self) -> DebugFull<'_, LENGTH, E, V> {
+        DebugFull { map: self }
     }
 
-    /// Returns true if the map contains no elements.
+    /// An iterator describing the per-key changes from `self` to `other`.
+    /// The iterator element type is `(E, MapDiff<&'a V>)`.
+    ///
+    /// Keys which are present in neither map are skipped.
     ///
     /// # Examples
     ///
     /// ```
-    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
-    /// use enumap::EnumMap;
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::{map::MapDiff, EnumMap};
     ///
-    /// let mut map = EnumMap::new();
-    /// assert!(map.is_empty());
-    /// map.insert(Fruit::Orange, 3);
-    /// assert!(!map.is_empty());
+    /// let old = EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 2), (Fruit::Grape, 3)]);
+    /// let new = EnumMap::from([(Fruit::Banana, 2), (Fruit::Grape, 4), (Fruit::Apple, 5)]);
+    ///
+    /// let diff: Vec<_> = old.diff(&new).collect();
+    /// assert_eq!(
+    ///     diff,
+    ///     vec![
+    ///         (Fruit::Orange, MapDiff::Removed(&1)),
+    ///         (Fruit::Banana, MapDiff::Unchanged(&2)),
+    ///         (Fruit::Grape, MapDiff::Changed(&3, &4)),
+    ///         (Fruit::Apple, MapDiff::Added(&5)),
+    ///     ]
+    /// );
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.data.iter().all(Option::is_none)
+    pub fn diff<'a>(&'a self, other: &'a Self) -> Diff<'a, LENGTH, E, V>
+    where
+        V: PartialEq,
+    {
+        Diff {
+            old: self,
+            new: other,
+            index: 0,
+        }
     }
 
-    /// An iterator visiting all key-value pairs in order, with references to the values.
-    /// The iterator element type is `(E, &'a V)`.
+    /// Gets the entry of the key for in-place manipulation.
     ///
     /// # Examples
     ///
     /// ```
-    /// # enumap::enumap! { #[derive(Debug)] enum Fruit { Orange, Banana, Grape, } }
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
     /// use enumap::EnumMap;
     ///
-    /// let mut map = EnumMap::from([
-    ///     (Fruit::Orange, 1),
-    ///     (Fruit::Banana, 2),
-    ///     (Fruit::Grape, 3),
-    /// ]);
-    ///
-    /// for (key, value) in map.iter() {
-    ///     println!("key: {key:?} value: {value}");
+    /// let mut counts = EnumMap::new();
+    /// for fruit in [Fruit::Orange, Fruit::Grape, Fruit::Orange] {
+    ///     *counts.entry(fruit).or_insert(0) += 1;
     /// }
-    /// # for (i, (k, value)) in map.iter().enumerate() {
-    /// #     assert_eq!(*value, i + 1);
-    /// #     assert_eq!(*value, map[k]);
-    /// # }
+    ///
+    /// assert_eq!(counts, EnumMap::from([(Fruit::Orange, 2), (Fruit::Grape, 1)]));
     /// ```
-    pub fn iter(&self) -> Iter<'_, LENGTH, E, V> {
-        Iter {
-            map: self,
-            index: 0,
+    pub fn entry(&mut self, key: E) -> Entry<'_, LENGTH, E, V> {
+        let slot = &mut self.data[E::to_index(key)];
+        match slot {
+            Some(_) => Entry::Occupied(OccupiedEntry { key, slot }),
+            None => Entry::Vacant(VacantEntry { key, slot }),
         }
     }
 
-    /// An iterator visiting all key-value pairs in order, with mutable references to the values.
-    /// The iterator element type is `(E, &'a mut V)`.
+    /// Creates an iterator which removes and yields all entries for which the predicate
+    /// returns `true`, in index order.
+    ///
+    /// Entries for which the predicate returns `false` remain in the map.
+    /// Entries are only removed while the iterator is advanced, if the iterator is dropped early
+    /// the remaining entries are kept.
     ///
     /// # Examples
     ///
     /// ```
-    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
     /// use enumap::EnumMap;
     ///
-    /// let mut map = EnumMap::from([
-    ///     (Fruit::Orange, 1),
-    ///     (Fruit::Banana, 2),
-    ///     (Fruit::Grape, 3),
-    /// ]);
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 2), (Fruit::Grape, 3)]);
     ///
-    /// for (_, value) in map.iter_mut() {
-    ///     *value *= 2;
-    /// }
+    /// let odd: Vec<_> = map.extract_if(|_, value| *value % 2 == 1).collect();
+    /// assert_eq!(odd, vec![(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    /// assert_eq!(map, EnumMap::from([(Fruit::Banana, 2)]));
     ///
-    /// assert_eq!(map[Fruit::Orange], 2);
-    /// assert_eq!(map[Fruit::Banana], 4);
-    /// assert_eq!(map[Fruit::Grape], 6);
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 2), (Fruit::Grape, 3)]);
+    ///
+    /// // Only the first matching entry is removed.
+    /// assert_eq!(map.extract_if(|_, _| true).next(), Some((Fruit::Orange, 1)));
+    /// assert_eq!(map, EnumMap::from([(Fruit::Banana, 2), (Fruit::Grape, 3)]));
     /// ```
-    pub fn iter_mut(&mut self) -> IterMut<'_, LENGTH, E, V> {
-        IterMut {
-            inner: self.data.iter_mut().enumerate(),
-            _enum: PhantomData,
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, LENGTH, E, V, F>
+    where
+        F: FnMut(E, &mut V) -> bool,
+    {
+        ExtractIf {
+            index: 0,
+            map: self,
+            pred,
         }
     }
 
-    /// An iterator visiting all keys in order. The iterator element type is `E`.
+    /// Sets the value of every key to a clone of `value`.
+    ///
+    /// Afterwards the map contains a value for every key.
     ///
     /// # Examples
     ///
     /// ```
-    /// # enumap::enumap! { #[derive(Debug)] enum Fruit { Orange, Banana, Grape, } }
-    /// use enumap::EnumMap;
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
     ///
-    /// let mut map = EnumMap::from([
-    ///     (Fruit::Orange, 1),
-    ///     (Fruit::Grape, 2),
-    /// ]);
+    /// let mut map = EnumMap::from([(Fruit::Banana, 2)]);
+    /// map.fill(0);
     ///
-    /// for key in map.keys() {
-    ///     println!("{key:?}");
-    /// }
-    /// # let mut iter = map.keys();
-    /// # assert!(matches!(iter.next(), Some(Fruit::Orange)));
-    /// # assert!(matches!(iter.next(), Some(Fruit::Grape)));
-    /// # assert!(iter.next().is_none());
+    /// assert_eq!(map.len(), Fruit::LENGTH);
+    /// assert!(map.values().all(|&v| v == 0));
     /// ```
-    pub fn keys(&self) -> Keys<'_, LENGTH, E, V> {
-        Keys { inner: self.iter() }
+    pub fn fill(&mut self, value: V)
+    where
+        V: Clone,
+    {
+        self.data.fill(Some(value));
     }
 
-    /// Returns the number of elements in the map.
+    /// Sets the value of every key to the value returned by `f` for that key.
+    ///
+    /// Afterwards the map contains a value for every key.
     ///
     /// # Examples
     ///
     /// ```
     /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
-    /// use enumap::EnumMap;
+    /// use enumap::{Enum, EnumMap};
     ///
-    /// let mut map = EnumMap::new();
-    /// assert_eq!(map.len(), 0);
-    /// map.insert(Fruit::Orange, "a");
-    /// assert_eq!(map.len(), 1);
+    /// let mut map = EnumMap::from([(Fruit::Banana, 100)]);
+    /// map.fill_with(|fruit| Fruit::to_index(fruit) * 10);
+    ///
+    /// assert_eq!(map.len(), Fruit::LENGTH);
+    /// assert_eq!(map[Fruit::Orange], 0);
+    /// assert_eq!(map[Fruit::Banana], 10);
+    /// assert_eq!(map[Fruit::Grape], 20);
     /// ```
-    pub fn len(&self) -> usize {
-        self.data.iter().filter(|v| v.is_some()).count()
+    pub fn fill_with<F: FnMut(E) -> V>(&mut self, mut f: F) {
+        for (index, value) in self.data.iter_mut().enumerate() {
+            if let Some(key) = E::from_index(index) {
+                *value = Some(f(key));
+            }
+        }
     }
 
-    /// Removes a key from the map, returning the value at the key if the key was previously in the map.
+    /// Transforms and filters the values of the map in a single pass.
+    ///
+    /// Entries for which `f` returns `None` are dropped,
+    /// all other entries are kept with the returned value.
     ///
     /// # Examples
     ///
     /// ```
-    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
     /// use enumap::EnumMap;
     ///
-    /// let mut map = EnumMap::new();
-    /// map.insert(Fruit::Orange, "a");
-    /// assert_eq!(map.remove(Fruit::Orange), Some("a"));
+    /// let map = EnumMap::from([(Fruit::Orange, 2), (Fruit::Banana, 3), (Fruit::Grape, 4)]);
+    /// let even = map.filter_map_values(|_, value| (value % 2 == 0).then(|| value.to_string()));
+    ///
+    /// assert_eq!(
+    ///     even,
+    ///     EnumMap::from([(Fruit::Orange, "2".to_owned()), (Fruit::Grape, "4".to_owned())])
+    /// );
     /// ```
-    pub fn remove(&mut self, key: E) -> Option<V> {
-        core::mem::take(&mut self.data[E::to_index(key)])
+    pub fn filter_map_values<U, F>(self, mut f: F) -> EnumMap<LENGTH, E, U>
+    where
+        F: FnMut(E, V) -> Option<U>,
+    {
+        let mut result = EnumMap::new();
+        for (key, value) in self {
+            if let Some(value) = f(key, value) {
+                result.insert(key, value);
+            }
+        }
+        result
     }
 
-    /// An iterator visiting all values in order. The iterator element type is `&'a V`.
+    /// Returns the first key, in index order, whose value is equal to `value`.
     ///
     /// # Examples
     ///
     /// ```
-    /// # enumap::enumap! { #[derive(Debug)] enum Fruit { Orange, Banana, Grape, } }
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 3), (Fruit::Grape, 3)]);
+    ///
+    /// assert_eq!(map.find_key(&3), Some(Fruit::Banana));
+    /// assert_eq!(map.find_key(&2), None);
+    /// ```
+    pub fn find_key(&self, value: &V) -> Option<E>
+    where
+        V: PartialEq,
+    {
+        self.find_keys(value).next()
+    }
+
+    /// An iterator visiting all keys, in index order, whose value is equal to `value`.
+    /// The iterator element type is `E`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 3), (Fruit::Grape, 3)]);
+    ///
+    /// let keys: Vec<_> = map.find_keys(&3).collect();
+    /// assert_eq!(keys, vec![Fruit::Banana, Fruit::Grape]);
+    ///
+    /// assert_eq!(map.find_keys(&2).next(), None);
+    /// ```
+    pub fn find_keys<'a>(&'a self, value: &'a V) -> FindKeys<'a, LENGTH, E, V>
+    where
+        V: PartialEq,
+    {
+        FindKeys {
+            inner: self.iter(),
+            value,
+        }
+    }
+
+    /// Returns the first key-value pair in the map, i.e. the pair with the lowest index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::new();
+    /// assert_eq!(map.first_key_value(), None);
+    ///
+    /// map.insert(Fruit::Grape, 3);
+    /// map.insert(Fruit::Banana, 2);
+    /// assert_eq!(map.first_key_value(), Some((Fruit::Banana, &2)));
+    /// ```
+    pub fn first_key_value(&self) -> Option<(E, &V)> {
+        self.data.iter().enumerate().find_map(|(index, value)| {
+            value
+                .as_ref()
+                .map(|value| (key_at::<LENGTH, E>(index), value))
+        })
+    }
+
+    /// Folds every present key-value pair into an accumulator, in index order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 5), (Fruit::Banana, 2), (Fruit::Grape, 3)]);
+    ///
+    /// let weighted = map.fold_values(0, |acc, fruit, value| acc + Fruit::to_index(fruit) * value);
+    /// assert_eq!(weighted, 0 * 5 + 1 * 2 + 2 * 3);
+    /// ```
+    pub fn fold_values<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, E, &V) -> B,
+    {
+        self.iter()
+            .fold(init, |acc, (key, value)| f(acc, key, value))
+    }
+
+    /// Creates an `EnumMap` from an iterator of key-value pairs.
+    ///
+    /// Behaves like the [`FromIterator`] implementation, later pairs overwrite earlier ones.
+    /// With `debug_assertions` enabled, every index returned by [`Enum::to_index`] is checked
+    /// to be in range, an invalid `Enum` implementation results in a descriptive panic
+    /// instead of an out of bounds slice access.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let map = EnumMap::<{ Fruit::LENGTH }, _, _>::from_entries([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Grape, 3),
+    ///     (Fruit::Orange, 2),
+    /// ]);
+    /// assert_eq!(map, EnumMap::from([(Fruit::Orange, 2), (Fruit::Grape, 3)]));
+    /// ```
+    pub fn from_entries<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (E, V)>,
+    {
+        let mut map = Self::new();
+        for (key, value) in iter {
+            let index = E::to_index(key);
+            debug_assert!(
+                index < LENGTH,
+                "invalid `Enum` implementation: `to_index` returned {index} which is out of range for length {LENGTH}"
+            );
+            map.data[index] = Some(value);
+        }
+        map
+    }
+
+    /// Creates an `EnumMap` from an iterator of key-value pairs, rejecting duplicate keys.
+    ///
+    /// Unlike the [`FromIterator`] implementation, which lets later pairs overwrite
+    /// earlier ones, this returns an error for the first key which appears more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let map = EnumMap::<{ Fruit::LENGTH }, _, _>::from_iter_unique([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Grape, 3),
+    /// ]);
+    /// assert_eq!(map, Ok(EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)])));
+    ///
+    /// let err = EnumMap::<{ Fruit::LENGTH }, _, _>::from_iter_unique([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Grape, 3),
+    ///     (Fruit::Grape, 4),
+    /// ]);
+    /// assert_eq!(err.unwrap_err().key(), Fruit::Grape);
+    /// ```
+    pub fn from_iter_unique<I>(iter: I) -> Result<Self, DuplicateKeyError<E>>
+    where
+        I: IntoIterator<Item = (E, V)>,
+    {
+        let mut map = Self::new();
+        for (key, value) in iter {
+            if map.insert(key, value).is_some() {
+                return Err(DuplicateKeyError { key });
+            }
+        }
+        Ok(map)
+    }
+
+    /// Creates an `EnumMap` from an iterator of key-value pairs, adding up the values of duplicate keys.
+    ///
+    /// Unlike the [`FromIterator`] implementation, which lets later pairs overwrite
+    /// earlier ones, the values of a key which appears more than once are accumulated
+    /// with [`Add`](core::ops::Add) in iteration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let deltas = [(Fruit::Orange, 2), (Fruit::Grape, 1), (Fruit::Orange, -5)];
+    ///
+    /// let summed = EnumMap::<{ Fruit::LENGTH }, _, _>::from_iter_summing(deltas);
+    /// assert_eq!(summed, EnumMap::from([(Fruit::Orange, -3), (Fruit::Grape, 1)]));
+    ///
+    /// let last_wins = EnumMap::<{ Fruit::LENGTH }, _, _>::from_iter(deltas);
+    /// assert_eq!(last_wins, EnumMap::from([(Fruit::Orange, -5), (Fruit::Grape, 1)]));
+    /// ```
+    pub fn from_iter_summing<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (E, V)>,
+        V: core::ops::Add<Output = V>,
+    {
+        let mut map = Self::new();
+        for (key, value) in iter {
+            let slot = &mut map.data[E::to_index(key)];
+            *slot = Some(match slot.take() {
+                Some(current) => current + value,
+                None => value,
+            });
+        }
+        map
+    }
+
+    /// Creates an `EnumMap` with a value for every key from an array of values.
+    ///
+    /// The value at index `i` is the value for the key `E::from_index(i)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let map = EnumMap::<{ Fruit::LENGTH }, Fruit, _>::from_values([1, 2, 3]);
+    ///
+    /// assert_eq!(map.len(), Fruit::LENGTH);
+    /// assert_eq!(map[Fruit::Banana], 2);
+    /// assert_eq!(map.into_values_array(), Some([1, 2, 3]));
+    /// ```
+    pub fn from_values(values: [V; LENGTH]) -> Self {
+        Self::from(values.map(Some))
+    }
+
+    /// Creates an `EnumMap` with every key mapped to the default value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let map = EnumMap::<{ Fruit::LENGTH }, Fruit, u32>::full_default();
+    ///
+    /// assert_eq!(map.len(), Fruit::LENGTH);
+    /// assert_eq!(map.get(Fruit::Orange), Some(&0));
+    /// assert_eq!(map.get(Fruit::Banana), Some(&0));
+    /// assert_eq!(map.get(Fruit::Grape), Some(&0));
+    /// ```
+    pub fn full_default() -> Self
+    where
+        V: Default,
+    {
+        let mut map = Self::new();
+        map.fill_with(|_| V::default());
+        map
+    }
+
+    /// Returns a reference to the value for the corresponding key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::new();
+    /// map.insert(Fruit::Orange, 3);
+    ///
+    /// assert_eq!(map.get(Fruit::Orange), Some(&3));
+    /// assert_eq!(map.get(Fruit::Banana), None);
+    /// ```
+    pub fn get(&self, key: E) -> Option<&V> {
+        self.data[E::to_index(key)].as_ref()
+    }
+
+    /// Returns a reference to the value stored at the raw index.
+    ///
+    /// Returns `None` if the index is out of range, i.e. `index >= LENGTH`, or the slot is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    ///
+    /// assert_eq!(map.get_by_index(2), Some(&3));
+    /// assert_eq!(map.get_by_index(1), None);
+    /// assert_eq!(map.get_by_index(3), None);
+    /// ```
+    pub fn get_by_index(&self, index: usize) -> Option<&V> {
+        self.data.get(index)?.as_ref()
+    }
+
+    /// Returns a mutable reference to the value stored at the raw index.
+    ///
+    /// Returns `None` if the index is out of range, i.e. `index >= LENGTH`, or the slot is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    ///
+    /// if let Some(value) = map.get_by_index_mut(0) {
+    ///     *value += 10;
+    /// }
+    /// assert_eq!(map[Fruit::Orange], 11);
+    ///
+    /// assert_eq!(map.get_by_index_mut(1), None);
+    /// assert_eq!(map.get_by_index_mut(3), None);
+    /// ```
+    pub fn get_by_index_mut(&mut self, index: usize) -> Option<&mut V> {
+        self.data.get_mut(index)?.as_mut()
+    }
+
+    /// Returns a reference to the value of the key.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the key is not present in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 3)]);
+    /// assert_eq!(map.expect_get(Fruit::Orange, "oranges must be in stock"), &3);
+    /// ```
+    ///
+    /// ```should_panic
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 3)]);
+    /// map.expect_get(Fruit::Grape, "grapes must be in stock");
+    /// ```
+    #[track_caller]
+    pub fn expect_get(&self, key: E, msg: &str) -> &V {
+        self.get(key).expect(msg)
+    }
+
+    /// Returns a clone of the value for the corresponding key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, String::from("orange"))]);
+    ///
+    /// assert_eq!(map.get_cloned(Fruit::Orange), Some(String::from("orange")));
+    /// assert_eq!(map.get_cloned(Fruit::Banana), None);
+    /// ```
+    pub fn get_cloned(&self, key: E) -> Option<V>
+    where
+        V: Clone,
+    {
+        self.get(key).cloned()
+    }
+
+    /// Returns a clone of the value for the corresponding key or `default` if the key is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, String::from("orange"))]);
+    ///
+    /// assert_eq!(map.get_cloned_or(Fruit::Orange, String::new()), "orange");
+    /// assert_eq!(map.get_cloned_or(Fruit::Banana, String::new()), "");
+    /// ```
+    pub fn get_cloned_or(&self, key: E, default: V) -> V
+    where
+        V: Clone,
+    {
+        self.get(key).cloned().unwrap_or(default)
+    }
+
+    /// Returns a copy of the value for the corresponding key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 3)]);
+    ///
+    /// assert_eq!(map.get_copied(Fruit::Orange), Some(3));
+    /// assert_eq!(map.get_copied(Fruit::Banana), None);
+    /// ```
+    pub fn get_copied(&self, key: E) -> Option<V>
+    where
+        V: Copy,
+    {
+        self.get(key).copied()
+    }
+
+    /// Returns the key-value pair corresponding to the supplied key.
+    ///
+    /// The returned key is reconstructed from the index of the key using [`Enum::from_index`].
+    /// This is useful for [`Enum`] implementations which map multiple values to the same index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// #[derive(Copy, Clone, Debug, PartialEq)]
+    /// enum Foo {
+    ///     Always,
+    ///     Maybe(bool),
+    /// }
+    ///
+    /// impl Enum<3> for Foo {
+    ///     fn from_index(index: usize) -> Option<Self> {
+    ///         match index {
+    ///             0 => Some(Self::Always),
+    ///             1 => Some(Self::Maybe(true)),
+    ///             2 => Some(Self::Maybe(false)),
+    ///             _ => None,
+    ///         }
+    ///     }
+    ///
+    ///     fn to_index(value: Self) -> usize {
+    ///         match value {
+    ///             Self::Always => 0,
+    ///             Self::Maybe(true) => 1,
+    ///             Self::Maybe(false) => 2,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let map = EnumMap::from([(Foo::Maybe(true), "bar")]);
+    ///
+    /// assert_eq!(map.get_key_value(Foo::Maybe(true)), Some((Foo::Maybe(true), &"bar")));
+    /// assert_eq!(map.get_key_value(Foo::Maybe(false)), None);
+    /// assert_eq!(map.get_key_value(Foo::Always), None);
+    /// ```
+    pub fn get_key_value(&self, key: E) -> Option<(E, &V)> {
+        let index = E::to_index(key);
+        let value = self.data[index].as_ref()?;
+        Some((key_at::<LENGTH, E>(index), value))
+    }
+
+    /// Returns a reference to the value for the corresponding key or `default` if the key is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 3)]);
+    ///
+    /// assert_eq!(map.get_or(Fruit::Orange, &0), &3);
+    /// assert_eq!(map.get_or(Fruit::Banana, &0), &0);
+    /// ```
+    pub fn get_or<'a>(&'a self, key: E, default: &'a V) -> &'a V {
+        self.get(key).unwrap_or(default)
+    }
+
+    /// Returns a mutable reference to the value of the key,
+    /// inserting the default value first if the key is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let basket = [Fruit::Orange, Fruit::Grape, Fruit::Orange];
+    ///
+    /// let mut histogram = EnumMap::new();
+    /// for fruit in basket {
+    ///     *histogram.get_or_insert_default(fruit) += 1;
+    /// }
+    ///
+    /// assert_eq!(histogram, EnumMap::from([(Fruit::Orange, 2), (Fruit::Grape, 1)]));
+    /// ```
+    pub fn get_or_insert_default(&mut self, key: E) -> &mut V
+    where
+        V: Default,
+    {
+        self.data[E::to_index(key)].get_or_insert_with(V::default)
+    }
+
+    /// Returns a mutable reference to the value of the key,
+    /// inserting the result of `f` called with the key first if the key is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let mut weights = EnumMap::from([(Fruit::Orange, 10)]);
+    ///
+    /// *weights.get_or_insert_with_key(Fruit::Orange, Fruit::to_index) += 1;
+    /// *weights.get_or_insert_with_key(Fruit::Grape, Fruit::to_index) += 1;
+    ///
+    /// assert_eq!(weights, EnumMap::from([(Fruit::Orange, 11), (Fruit::Grape, 3)]));
+    /// ```
+    pub fn get_or_insert_with_key<F: FnOnce(E) -> V>(&mut self, key: E, f: F) -> &mut V {
+        self.data[E::to_index(key)].get_or_insert_with(|| f(key))
+    }
+
+    /// Returns a mutable reference to the value for the corresponding key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::new();
+    /// map.insert(Fruit::Orange, 3);
+    ///
+    /// if let Some(value) = map.get_mut(Fruit::Orange) {
+    ///     *value += 2;
+    /// }
+    /// assert_eq!(map[Fruit::Orange], 5);
+    /// ```
+    pub fn get_mut(&mut self, key: E) -> Option<&mut V> {
+        self.data[E::to_index(key)].as_mut()
+    }
+
+    /// Returns mutable references to the values of two distinct keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` and `b` are the same key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    ///
+    /// if let (Some(orange), Some(grape)) = map.get2_mut(Fruit::Orange, Fruit::Grape) {
+    ///     core::mem::swap(orange, grape);
+    /// }
+    /// assert_eq!(map[Fruit::Orange], 3);
+    /// assert_eq!(map[Fruit::Grape], 1);
+    ///
+    /// let (grape, banana) = map.get2_mut(Fruit::Grape, Fruit::Banana);
+    /// assert_eq!(grape, Some(&mut 1));
+    /// assert_eq!(banana, None);
+    /// ```
+    ///
+    /// Requesting the same key twice panics:
+    ///
+    /// ```should_panic
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1)]);
+    /// map.get2_mut(Fruit::Orange, Fruit::Orange);
+    /// ```
+    pub fn get2_mut(&mut self, a: E, b: E) -> (Option<&mut V>, Option<&mut V>) {
+        let a = E::to_index(a);
+        let b = E::to_index(b);
+        assert_ne!(a, b, "get2_mut called with the same key twice");
+
+        let (low, high) = (a.min(b), a.max(b));
+        let (left, right) = self.data.split_at_mut(high);
+        let (low, high) = (left[low].as_mut(), right[0].as_mut());
+
+        if a < b {
+            (low, high)
+        } else {
+            (high, low)
+        }
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map already had a value present for the key,
+    /// the old value is returned. See also [`EnumMap::replace`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::new();
+    /// assert_eq!(map.insert(Fruit::Orange, 3), None);
+    /// assert_eq!(map.insert(Fruit::Orange, 5), Some(3));
+    /// ```
+    pub fn insert(&mut self, key: E, value: V) -> Option<V> {
+        self.data[E::to_index(key)].replace(value)
+    }
+
+    /// Inserts a key-value pair into the map, unless the number of present entries
+    /// would exceed `max_len`.
+    ///
+    /// Updating the value of a key which is already present is always allowed and returns
+    /// the old value. If the insert is rejected, the passed value is returned as the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::new();
+    /// assert_eq!(map.insert_within_limit(Fruit::Orange, 1, 2), Ok(None));
+    /// assert_eq!(map.insert_within_limit(Fruit::Banana, 2, 2), Ok(None));
+    ///
+    /// // The limit is reached, new keys are rejected.
+    /// assert_eq!(map.insert_within_limit(Fruit::Grape, 3, 2), Err(3));
+    /// assert!(!map.contains_key(Fruit::Grape));
+    ///
+    /// // Existing keys can still be updated.
+    /// assert_eq!(map.insert_within_limit(Fruit::Orange, 10, 2), Ok(Some(1)));
+    /// assert_eq!(map[Fruit::Orange], 10);
+    /// ```
+    pub fn insert_within_limit(
+        &mut self,
+        key: E,
+        value: V,
+        max_len: usize,
+    ) -> Result<Option<V>, V> {
+        let index = E::to_index(key);
+        if self.data[index].is_none() && self.len() >= max_len {
+            return Err(value);
+        }
+        Ok(self.data[index].replace(value))
+    }
+
+    /// Consumes the map and returns all present entries in a `Vec`, ordered by index.
+    ///
+    /// The order is the same as the one of [`EnumMap::into_iter`], the vector is allocated
+    /// with the exact number of entries up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Grape, 1), (Fruit::Orange, 2)]);
+    /// assert_eq!(map.into_sorted_vec(), vec![(Fruit::Orange, 2), (Fruit::Grape, 1)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_sorted_vec(self) -> alloc::vec::Vec<(E, V)> {
+        let mut vec = alloc::vec::Vec::with_capacity(self.len());
+        vec.extend(self);
+        vec
+    }
+
+    /// Consumes the map and returns all present entries in a `Vec`, ordered by value.
+    ///
+    /// The sort is stable, entries with equal values remain ordered by index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 3), (Fruit::Banana, 1), (Fruit::Grape, 1)]);
+    /// assert_eq!(
+    ///     map.into_sorted_vec_by_value(),
+    ///     vec![(Fruit::Banana, 1), (Fruit::Grape, 1), (Fruit::Orange, 3)]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_sorted_vec_by_value(self) -> alloc::vec::Vec<(E, V)>
+    where
+        V: Ord,
+    {
+        let mut vec = self.into_sorted_vec();
+        vec.sort_by(|(_, a), (_, b)| a.cmp(b));
+        vec
+    }
+
+    /// Creates a consuming iterator visiting all the values in order.
+    /// The map cannot be used after calling this. The iterator element type is `V`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([
+    ///     (Fruit::Grape, 3),
+    ///     (Fruit::Banana, 2),
+    ///     (Fruit::Orange, 1),
+    /// ]);
+    ///
+    /// let vec: Vec<i32> = map.clone().into_values().collect();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    ///
+    /// let vec: Vec<i32> = map.into_values().rev().collect();
+    /// assert_eq!(vec, vec![3, 2, 1]);
+    /// ```
+    pub fn into_values(self) -> IntoValues<LENGTH, E, V> {
+        IntoValues {
+            inner: self.into_iter(),
+        }
+    }
+
+    /// Converts the map into an array of values, if every key is present.
+    ///
+    /// The value for the key `E::from_index(i)` is stored at index `i`.
+    /// Returns `None` if any key is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    /// assert_eq!(map.into_values_array(), None);
+    ///
+    /// map.insert(Fruit::Banana, 2);
+    /// assert_eq!(map.into_values_array(), Some([1, 2, 3]));
+    /// ```
+    pub fn into_values_array(self) -> Option<[V; LENGTH]> {
+        if self.data.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(self.data.map(Option::unwrap))
+    }
+
+    /// Inverts the map, grouping the keys by their value.
+    ///
+    /// Every value is converted into a variant of the enum `F`,
+    /// the returned map contains the set of original keys for each of those variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{EnumMap, EnumSet};
+    ///
+    /// enumap::enumap! {
+    ///     #[derive(Debug, PartialEq)]
+    ///     enum Color {
+    ///         Orange,
+    ///         Yellow,
+    ///     }
+    /// }
+    ///
+    /// let colors = EnumMap::from([
+    ///     (Fruit::Orange, Color::Orange),
+    ///     (Fruit::Banana, Color::Yellow),
+    /// ]);
+    /// let by_color = colors.invert();
+    ///
+    /// assert_eq!(by_color[Color::Orange], EnumSet::from([Fruit::Orange]));
+    /// assert_eq!(by_color[Color::Yellow], EnumSet::from([Fruit::Banana]));
+    /// ```
+    pub fn invert<const L2: usize, F: Enum<L2>>(self) -> EnumMap<L2, F, EnumSet<LENGTH, E>>
+    where
+        V: Into<F>,
+    {
+        let mut result = EnumMap::<L2, F, EnumSet<LENGTH, E>>::new();
+        for (key, value) in self {
+            result.data[F::to_index(value.into())]
+                .get_or_insert_with(EnumSet::new)
+                .insert(key);
+        }
+        result
+    }
+
+    /// Returns true if the map contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::new();
+    /// assert!(map.is_empty());
+    /// map.insert(Fruit::Orange, 3);
+    /// assert!(!map.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.iter().all(Option::is_none)
+    }
+
+    /// Returns true if every key of the enum has a value in the map.
+    ///
+    /// A total map can be converted into a plain array of values with [`EnumMap::into_values_array`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 2)]);
+    /// assert!(!map.is_total());
+    ///
+    /// map.insert(Fruit::Grape, 3);
+    /// assert!(map.is_total());
+    /// assert_eq!(map.into_values_array(), Some([1, 2, 3]));
+    /// ```
+    pub fn is_total(&self) -> bool {
+        self.data.iter().all(Option::is_some)
+    }
+
+    /// An iterator visiting all key-value pairs in order, with references to the values.
+    /// The iterator element type is `(E, &'a V)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Banana, 2),
+    ///     (Fruit::Grape, 3),
+    /// ]);
+    ///
+    /// for (key, value) in map.iter() {
+    ///     println!("key: {key:?} value: {value}");
+    /// }
+    /// # for (i, (k, value)) in map.iter().enumerate() {
+    /// #     assert_eq!(*value, i + 1);
+    /// #     assert_eq!(*value, map[k]);
+    /// # }
+    /// ```
+    pub fn iter(&self) -> Iter<'_, LENGTH, E, V> {
+        Iter {
+            map: self,
+            index: 0,
+            back: LENGTH,
+        }
+    }
+
+    /// An iterator visiting all keys in order, with optional references to the values.
+    /// The iterator element type is `(E, Option<&'a V>)`.
+    ///
+    /// Unlike [`EnumMap::iter`], keys without a value are also visited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Grape, 3),
+    /// ]);
+    ///
+    /// let report: Vec<String> = map
+    ///     .iter_full()
+    ///     .map(|(key, value)| match value {
+    ///         Some(value) => format!("{key:?}: {value}"),
+    ///         None => format!("{key:?}: -"),
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(report, vec!["Orange: 1", "Banana: -", "Grape: 3"]);
+    /// ```
+    pub fn iter_full(&self) -> IterFull<'_, LENGTH, E, V> {
+        IterFull {
+            inner: self.data.iter().enumerate(),
+            _enum: PhantomData,
+        }
+    }
+
+    /// An iterator visiting all present values in order, together with their index.
+    /// The iterator element type is `(usize, &'a V)`.
+    ///
+    /// The index is the one returned by [`Enum::to_index`] for the key of the value.
+    /// Unlike [`EnumMap::iter`], keys are never reconstructed through [`Enum::from_index`],
+    /// which avoids its cost for hand-written implementations when only the index is needed,
+    /// for example to index into another array of the same length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 3), (Fruit::Grape, 5)]);
+    /// let weights = [10, 20, 30];
+    ///
+    /// let total: i32 = map.iter_indexed().map(|(i, v)| weights[i] * v).sum();
+    /// assert_eq!(total, 180);
+    ///
+    /// for ((index, _), fruit) in map.iter_indexed().zip(map.keys()) {
+    ///     assert_eq!(index, Fruit::to_index(fruit));
+    /// }
+    /// ```
+    pub fn iter_indexed(&self) -> IterIndexed<'_, LENGTH, E, V> {
+        IterIndexed {
+            inner: self.data.iter().enumerate(),
+            _enum: PhantomData,
+        }
+    }
+
+    /// An iterator visiting all key-value pairs in order, with mutable references to the values.
+    /// The iterator element type is `(E, &'a mut V)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Banana, 2),
+    ///     (Fruit::Grape, 3),
+    /// ]);
+    ///
+    /// for (_, value) in map.iter_mut() {
+    ///     *value *= 2;
+    /// }
+    ///
+    /// assert_eq!(map[Fruit::Orange], 2);
+    /// assert_eq!(map[Fruit::Banana], 4);
+    /// assert_eq!(map[Fruit::Grape], 6);
+    ///
+    /// // Visit the values in reverse order.
+    /// let mut acc = 0;
+    /// for (_, value) in map.iter_mut().rev() {
+    ///     acc += *value;
+    ///     *value = acc;
+    /// }
+    ///
+    /// assert_eq!(map[Fruit::Orange], 12);
+    /// assert_eq!(map[Fruit::Banana], 10);
+    /// assert_eq!(map[Fruit::Grape], 6);
+    /// # let mut iter = map.iter_mut();
+    /// # assert_eq!(iter.size_hint(), (0, Some(3)));
+    /// # iter.next_back();
+    /// # assert_eq!(iter.size_hint(), (0, Some(2)));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, LENGTH, E, V> {
+        IterMut {
+            inner: self.data.iter_mut().enumerate(),
+            _enum: PhantomData,
+        }
+    }
+
+    /// An iterator visiting all keys in order, with mutable references to the value slots.
+    /// The iterator element type is `(E, &'a mut Option<V>)`.
+    ///
+    /// Unlike [`EnumMap::iter_mut`], keys without a value are also visited,
+    /// values can be inserted and removed through the slots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    ///
+    /// for (fruit, slot) in map.iter_mut_full() {
+    ///     match slot {
+    ///         Some(_) if fruit == Fruit::Grape => *slot = None,
+    ///         Some(_) => {}
+    ///         None => *slot = Some(0),
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(map, EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 0)]));
+    /// ```
+    pub fn iter_mut_full(&mut self) -> IterMutFull<'_, LENGTH, E, V> {
+        IterMutFull {
+            inner: self.data.iter_mut().enumerate(),
+            _enum: PhantomData,
+        }
+    }
+
+    /// An iterator visiting all key-value pairs in ascending order of the values.
+    /// The iterator element type is `(E, &'a V)`.
+    ///
+    /// Entries with equal values are visited in index order. The entries are sorted
+    /// in a fixed size buffer, the iterator does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 3), (Fruit::Banana, 1), (Fruit::Grape, 3)]);
+    ///
+    /// let sorted: Vec<_> = map.iter_sorted_by_value().collect();
+    /// assert_eq!(
+    ///     sorted,
+    ///     vec![(Fruit::Banana, &1), (Fruit::Orange, &3), (Fruit::Grape, &3)]
+    /// );
+    /// ```
+    pub fn iter_sorted_by_value(&self) -> IterSortedByValue<'_, LENGTH, E, V>
+    where
+        V: Ord,
+    {
+        let mut indices = [0; LENGTH];
+        let mut len = 0;
+        for (index, value) in self.data.iter().enumerate() {
+            if value.is_some() {
+                indices[len] = index;
+                len += 1;
+            }
+        }
+
+        indices[..len].sort_unstable_by(|&a, &b| {
+            self.data[a]
+                .as_ref()
+                .cmp(&self.data[b].as_ref())
+                .then(a.cmp(&b))
+        });
+
+        IterSortedByValue {
+            indices,
+            index: 0,
+            len,
+            map: self,
+        }
+    }
+
+    /// Returns the set of present keys and the set of absent keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap, EnumSet};
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    /// let (present, absent) = map.key_presence();
+    ///
+    /// assert_eq!(present, EnumSet::from([Fruit::Orange, Fruit::Grape]));
+    /// assert_eq!(absent, EnumSet::from([Fruit::Banana]));
+    ///
+    /// assert!(present.is_disjoint(&absent));
+    /// assert_eq!(&present | &absent, EnumSet::<{ Fruit::LENGTH }, Fruit>::all());
+    /// ```
+    pub fn key_presence(&self) -> (EnumSet<LENGTH, E>, EnumSet<LENGTH, E>) {
+        let present = EnumSet::from(self);
+        let absent = &EnumSet::all() - &present;
+        (present, absent)
+    }
+
+    /// An iterator visiting all keys in order. The iterator element type is `E`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Grape, 2),
+    /// ]);
+    ///
+    /// for key in map.keys() {
+    ///     println!("{key:?}");
+    /// }
+    ///
+    /// // Keys in reverse order.
+    /// for key in map.keys().rev() {
+    ///     println!("{key:?}");
+    /// }
+    /// # let mut iter = map.keys();
+    /// # assert!(matches!(iter.next(), Some(Fruit::Orange)));
+    /// # assert!(matches!(iter.next(), Some(Fruit::Grape)));
+    /// # assert!(iter.next().is_none());
+    /// # let mut iter = map.keys().rev();
+    /// # assert!(matches!(iter.next(), Some(Fruit::Grape)));
+    /// # assert!(matches!(iter.next(), Some(Fruit::Orange)));
+    /// # assert!(iter.next().is_none());
+    /// ```
+    pub fn keys(&self) -> Keys<'_, LENGTH, E, V> {
+        Keys { inner: self.iter() }
+    }
+
+    /// Returns the last key-value pair in the map, i.e. the pair with the highest index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::new();
+    /// assert_eq!(map.last_key_value(), None);
+    ///
+    /// map.insert(Fruit::Orange, 1);
+    /// map.insert(Fruit::Banana, 2);
+    /// assert_eq!(map.last_key_value(), Some((Fruit::Banana, &2)));
+    /// ```
+    pub fn last_key_value(&self) -> Option<(E, &V)> {
+        self.data
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(index, value)| {
+                value
+                    .as_ref()
+                    .map(|value| (key_at::<LENGTH, E>(index), value))
+            })
+    }
+
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::new();
+    /// assert_eq!(map.len(), 0);
+    /// map.insert(Fruit::Orange, "a");
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.data.iter().filter(|v| v.is_some()).count()
+    }
+
+    /// Moves every value of the map to a new key of a different enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if two keys are mapped to the same new key, see [`EnumMap::try_map_keys`]
+    /// for a fallible version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// enumap::enumap! {
+    ///     #[derive(Debug, PartialEq)]
+    ///     enum Color {
+    ///         Orange,
+    ///         Yellow,
+    ///         Purple,
+    ///     }
+    /// }
+    ///
+    /// let map = EnumMap::from([(Fruit::Banana, 2), (Fruit::Grape, 3)]);
+    /// let by_color = map.map_keys(|fruit| match fruit {
+    ///     Fruit::Orange => Color::Orange,
+    ///     Fruit::Banana => Color::Yellow,
+    ///     Fruit::Grape => Color::Purple,
+    /// });
+    ///
+    /// assert_eq!(by_color, EnumMap::from([(Color::Yellow, 2), (Color::Purple, 3)]));
+    /// ```
+    #[track_caller]
+    pub fn map_keys<const L2: usize, F, G>(self, g: G) -> EnumMap<L2, F, V>
+    where
+        F: Enum<L2>,
+        G: FnMut(E) -> F,
+    {
+        match self.try_map_keys(g) {
+            Ok(map) => map,
+            Err(err) => panic!(
+                "multiple keys mapped to the key at index {}",
+                F::to_index(err.key())
+            ),
+        }
+    }
+
+    /// Splits the map into two maps according to a predicate.
+    ///
+    /// Entries for which `f` returns `true` are moved into the first map,
+    /// all other entries into the second map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{EnumMap, EnumSet};
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 2), (Fruit::Grape, 3)]);
+    /// let (odd, even) = map.clone().partition(|_, value| value % 2 == 1);
+    ///
+    /// assert_eq!(odd, EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]));
+    /// assert_eq!(even, EnumMap::from([(Fruit::Banana, 2)]));
+    ///
+    /// assert!(EnumSet::from(&odd).is_disjoint(&EnumSet::from(&even)));
+    /// assert_eq!(odd.into_iter().chain(even).collect::<EnumMap<_, _, _>>(), map);
+    /// ```
+    pub fn partition<F: FnMut(E, &V) -> bool>(self, mut f: F) -> (Self, Self) {
+        let mut left = Self::new();
+        let mut right = Self::new();
+        for (key, value) in self {
+            if f(key, &value) {
+                left.insert(key, value);
+            } else {
+                right.insert(key, value);
+            }
+        }
+        (left, right)
+    }
+
+    /// Removes and returns the first key-value pair in the map,
+    /// i.e. the pair with the lowest index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([
+    ///     (Fruit::Grape, 3),
+    ///     (Fruit::Orange, 1),
+    /// ]);
+    ///
+    /// assert_eq!(map.pop_first(), Some((Fruit::Orange, 1)));
+    /// assert_eq!(map.pop_first(), Some((Fruit::Grape, 3)));
+    /// assert_eq!(map.pop_first(), None);
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn pop_first(&mut self) -> Option<(E, V)> {
+        let index = self.data.iter().position(Option::is_some)?;
+        Some((key_at::<LENGTH, E>(index), self.data[index].take()?))
+    }
+
+    /// Removes and returns the last key-value pair in the map,
+    /// i.e. the pair with the highest index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Banana, 2),
+    ///     (Fruit::Grape, 3),
+    /// ]);
+    ///
+    /// let mut popped = Vec::new();
+    /// while let Some(entry) = map.pop_last() {
+    ///     popped.push(entry);
+    /// }
+    ///
+    /// assert_eq!(popped, vec![(Fruit::Grape, 3), (Fruit::Banana, 2), (Fruit::Orange, 1)]);
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn pop_last(&mut self) -> Option<(E, V)> {
+        let index = self.data.iter().rposition(Option::is_some)?;
+        Some((key_at::<LENGTH, E>(index), self.data[index].take()?))
+    }
+
+    /// An iterator visiting the indices of all present keys in order.
+    /// The iterator element type is `usize`.
+    ///
+    /// Unlike [`EnumMap::keys`] the keys are not reconstructed using [`Enum::from_index`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    ///
+    /// let indices: Vec<usize> = map.present_indices().collect();
+    /// assert_eq!(indices, vec![0, 2]);
+    ///
+    /// for (index, fruit) in map.present_indices().zip(map.keys()) {
+    ///     assert_eq!(index, Fruit::to_index(fruit));
+    /// }
+    /// ```
+    pub fn present_indices(&self) -> PresentIndices<'_, LENGTH, E, V> {
+        PresentIndices {
+            inner: self.data.iter().enumerate(),
+            _enum: PhantomData,
+        }
+    }
+
+    /// Returns the product of all values in the map.
+    ///
+    /// An empty map returns the multiplicative identity as defined by [`Product`](core::iter::Product).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 2), (Fruit::Grape, 5)]);
+    /// assert_eq!(map.product_values(), 10);
+    /// ```
+    pub fn product_values(&self) -> V
+    where
+        V: core::iter::Product + Copy,
+    {
+        self.values().copied().product()
+    }
+
+    /// Creates a map over the smaller enum `F` by cloning the value of the key of `self`
+    /// which `g` maps each variant of `F` to.
+    ///
+    /// Variants of `F` whose key is absent in `self` are absent in the returned map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::EnumMap;
+    ///
+    /// enumap::enumap! {
+    ///     #[derive(Debug, PartialEq)]
+    ///     enum Citrus {
+    ///         Orange,
+    ///         Lemon,
+    ///     }
+    /// }
+    ///
+    /// let stock = EnumMap::from([(Fruit::Orange, 10), (Fruit::Banana, 20), (Fruit::Apple, 5)]);
+    ///
+    /// let citrus = stock.project(|citrus| match citrus {
+    ///     Citrus::Orange => Fruit::Orange,
+    ///     Citrus::Lemon => Fruit::Grape,
+    /// });
+    ///
+    /// assert_eq!(citrus, EnumMap::from([(Citrus::Orange, 10)]));
+    /// ```
+    pub fn project<const L2: usize, F, G>(&self, mut g: G) -> EnumMap<L2, F, V>
+    where
+        F: Enum<L2>,
+        G: FnMut(F) -> E,
+        V: Clone,
+    {
+        let mut result = EnumMap::new();
+        for index in 0..L2 {
+            let key = key_at::<L2, F>(index);
+            if let Some(value) = self.get(g(key)) {
+                result.insert(key, value.clone());
+            }
+        }
+        result
+    }
+
+    /// Reduces the present values to a single value by repeatedly applying `f`, in index order.
+    ///
+    /// Returns `None` if the map is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 5), (Fruit::Banana, 8), (Fruit::Grape, 3)]);
+    /// assert_eq!(map.reduce_values(i32::max), Some(8));
+    ///
+    /// let empty = EnumMap::<3, Fruit, i32>::new();
+    /// assert_eq!(empty.reduce_values(i32::max), None);
+    /// ```
+    pub fn reduce_values<F: FnMut(V, V) -> V>(self, f: F) -> Option<V> {
+        self.into_values().reduce(f)
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key was previously in the map.
+    /// See also [`EnumMap::take`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::new();
+    /// map.insert(Fruit::Orange, "a");
+    /// assert_eq!(map.remove(Fruit::Orange), Some("a"));
+    /// ```
+    pub fn remove(&mut self, key: E) -> Option<V> {
+        core::mem::take(&mut self.data[E::to_index(key)])
+    }
+
+    /// Replaces the value of a key, returning the previous value if the key was present.
+    ///
+    /// This is an alias for [`EnumMap::insert`], named after [`Option::replace`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::new();
+    /// assert_eq!(map.replace(Fruit::Orange, "a"), None);
+    /// assert_eq!(map.replace(Fruit::Orange, "b"), Some("a"));
+    /// assert_eq!(map[Fruit::Orange], "b");
+    /// ```
+    pub fn replace(&mut self, key: E, value: V) -> Option<V> {
+        self.insert(key, value)
+    }
+
+    /// Does nothing, the map can always hold an entry for every key.
+    ///
+    /// Exists for compatibility with generic code written against maps which allocate.
+    /// The capacity of the map is always `LENGTH`, see [`EnumMap::capacity`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let mut map = EnumMap::<{ Fruit::LENGTH }, Fruit, i32>::new();
+    /// map.reserve(100);
+    /// assert_eq!(map.capacity(), Fruit::LENGTH);
+    /// ```
+    pub fn reserve(&mut self, _additional: usize) {}
+
+    /// Replaces every present value with its default value, keeping the set of present keys.
+    ///
+    /// Unlike [`EnumMap::clear`] no keys are removed and unlike [`EnumMap::fill`]
+    /// absent keys stay absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut counts = EnumMap::from([(Fruit::Orange, 3), (Fruit::Grape, 5)]);
+    /// counts.reset_values();
+    ///
+    /// assert_eq!(counts, EnumMap::from([(Fruit::Orange, 0), (Fruit::Grape, 0)]));
+    /// assert!(!counts.contains_key(Fruit::Banana));
+    /// ```
+    pub fn reset_values(&mut self)
+    where
+        V: Default,
+    {
+        for value in self.values_mut() {
+            *value = V::default();
+        }
+    }
+
+    /// Retains only the elements specified by the predicate.
+    ///
+    /// In other words, remove all pairs `(k, v)` for which `f(k, &mut v)` returns `false`.
+    /// The elements are visited in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Banana, 2),
+    ///     (Fruit::Grape, 3),
+    /// ]);
+    ///
+    /// map.retain(|fruit, _| fruit != Fruit::Banana);
+    /// assert_eq!(map, EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]));
+    /// ```
+    pub fn retain<F: FnMut(E, &mut V) -> bool>(&mut self, f: F) {
+        self.retain_count(f);
+    }
+
+    /// Retains only the elements specified by the predicate and returns the number of removed elements.
+    ///
+    /// Behaves like [`EnumMap::retain`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Banana, 2),
+    ///     (Fruit::Grape, 3),
+    /// ]);
+    ///
+    /// let len = map.len();
+    /// let removed = map.retain_count(|_, value| *value % 2 == 0);
+    ///
+    /// assert_eq!(removed, 2);
+    /// assert_eq!(removed, len - map.len());
+    /// assert_eq!(map, EnumMap::from([(Fruit::Banana, 2)]));
+    /// ```
+    pub fn retain_count<F: FnMut(E, &mut V) -> bool>(&mut self, mut f: F) -> usize {
+        let mut removed = 0;
+        for (index, slot) in self.data.iter_mut().enumerate() {
+            if let Some(value) = slot {
+                if !f(key_at::<LENGTH, E>(index), value) {
+                    *slot = None;
+                    removed += 1;
+                }
+            }
+        }
+        removed
+    }
+
+    /// Retains only the keys contained in `keep`, removing all other key-value pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::{EnumMap, EnumSet};
+    ///
+    /// let mut map = EnumMap::from([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Banana, 2),
+    ///     (Fruit::Grape, 3),
+    ///     (Fruit::Apple, 4),
+    /// ]);
+    ///
+    /// map.retain_keys(&EnumSet::from([Fruit::Banana, Fruit::Apple]));
+    /// assert_eq!(map, EnumMap::from([(Fruit::Banana, 2), (Fruit::Apple, 4)]));
+    /// ```
+    pub fn retain_keys(&mut self, keep: &EnumSet<LENGTH, E>) {
+        for (value, keep) in self.data.iter_mut().zip(keep.0.as_slice()) {
+            if keep.is_none() {
+                *value = None;
+            }
+        }
+    }
+
+    /// Retains only the values specified by the predicate.
+    ///
+    /// In other words, remove all values `v` for which `f(&mut v)` returns `false`.
+    /// This is a shorthand for [`EnumMap::retain`] when the predicate does not need the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Banana, 20),
+    ///     (Fruit::Grape, 3),
+    /// ]);
+    ///
+    /// map.retain_values(|&mut v| v >= 3);
+    /// assert_eq!(map, EnumMap::from([(Fruit::Banana, 20), (Fruit::Grape, 3)]));
+    /// ```
+    pub fn retain_values<F: FnMut(&mut V) -> bool>(&mut self, mut f: F) {
+        self.retain(|_, v| f(v))
+    }
+
+    /// Returns the sum of all values in the map.
+    ///
+    /// An empty map returns the additive identity as defined by [`Sum`](core::iter::Sum).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut counts = EnumMap::new();
+    /// assert_eq!(counts.sum_values(), 0);
+    ///
+    /// counts.insert(Fruit::Orange, 3);
+    /// counts.insert(Fruit::Grape, 4);
+    /// assert_eq!(counts.sum_values(), 7);
+    /// ```
+    pub fn sum_values(&self) -> V
+    where
+        V: core::iter::Sum + Copy,
+    {
+        self.values().copied().sum()
+    }
+
+    /// Returns the sum of all values in the map, saturating at the numeric bounds instead of overflowing.
+    ///
+    /// An empty map returns `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let counts = EnumMap::from([(Fruit::Orange, 200u8), (Fruit::Grape, 100)]);
+    /// assert_eq!(counts.sum_values_saturating(), u8::MAX);
+    /// ```
+    pub fn sum_values_saturating(&self) -> V
+    where
+        V: SaturatingAdd,
+    {
+        self.values()
+            .fold(V::ZERO, |acc, &value| acc.saturating_add(value))
+    }
+
+    /// Takes the value of a key out of the map, leaving the key absent.
+    ///
+    /// This is an alias for [`EnumMap::remove`], named after [`Option::take`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, "a")]);
+    /// assert_eq!(map.take(Fruit::Orange), Some("a"));
+    /// assert_eq!(map.take(Fruit::Orange), None);
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn take(&mut self, key: E) -> Option<V> {
+        self.remove(key)
+    }
+
+    /// Returns mutable references to the values of `K` distinct keys at once.
+    ///
+    /// Absent keys result in `None` at their position. Unlike [`EnumMap::get2_mut`] this does
+    /// not panic when a key is requested more than once but returns a [`DisjointError`]
+    /// naming the first repeated key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    ///
+    /// let [grape, banana, orange] = map
+    ///     .try_get_disjoint_mut([Fruit::Grape, Fruit::Banana, Fruit::Orange])
+    ///     .unwrap();
+    /// assert_eq!(banana, None);
+    /// core::mem::swap(grape.unwrap(), orange.unwrap());
+    /// assert_eq!(map, EnumMap::from([(Fruit::Orange, 3), (Fruit::Grape, 1)]));
+    ///
+    /// let err = map.try_get_disjoint_mut([Fruit::Orange, Fruit::Grape, Fruit::Orange]);
+    /// assert_eq!(err.unwrap_err().key(), Fruit::Orange);
+    /// ```
+    pub fn try_get_disjoint_mut<const K: usize>(
+        &mut self,
+        keys: [E; K],
+    ) -> Result<[Option<&mut V>; K], DisjointError<E>> {
+        let indices = keys.map(E::to_index);
+        for (i, index) in indices.iter().enumerate() {
+            if indices[..i].contains(index) {
+                return Err(DisjointError { key: keys[i] });
+            }
+        }
+
+        let mut result = [(); K].map(|_| None);
+        for (index, slot) in self.data.iter_mut().enumerate() {
+            if let Some(position) = indices.iter().position(|&i| i == index) {
+                result[position] = slot.as_mut();
+            }
+        }
+        Ok(result)
+    }
+
+    /// Transforms every present value of the map with a fallible closure.
+    ///
+    /// Returns the first error returned by `f`, values which have already been
+    /// transformed are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, "1"), (Fruit::Grape, "3")]);
+    /// let parsed = map.try_map(|_, value| value.parse::<u32>());
+    /// assert_eq!(parsed, Ok(EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)])));
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, "1"), (Fruit::Banana, "x"), (Fruit::Grape, "y")]);
+    /// let parsed = map.try_map(|fruit, value| value.parse::<u32>().map_err(|_| fruit));
+    /// assert_eq!(parsed, Err(Fruit::Banana));
+    /// ```
+    pub fn try_map<U, Err, F>(self, mut f: F) -> Result<EnumMap<LENGTH, E, U>, Err>
+    where
+        F: FnMut(E, V) -> Result<U, Err>,
+    {
+        let mut result = EnumMap::new();
+        for (key, value) in self {
+            result.insert(key, f(key, value)?);
+        }
+        Ok(result)
+    }
+
+    /// Moves every value of the map to a new key of a different enum.
+    ///
+    /// Returns an error with the new key, if two keys are mapped to the same new key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// enumap::enumap! {
+    ///     #[derive(Debug, PartialEq)]
+    ///     enum Taste {
+    ///         Sour,
+    ///         Sweet,
+    ///     }
+    /// }
+    ///
+    /// let taste = |fruit| match fruit {
+    ///     Fruit::Orange => Taste::Sour,
+    ///     Fruit::Banana | Fruit::Grape => Taste::Sweet,
+    /// };
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    /// assert_eq!(
+    ///     map.try_map_keys(taste),
+    ///     Ok(EnumMap::from([(Taste::Sour, 1), (Taste::Sweet, 3)]))
+    /// );
+    ///
+    /// let map = EnumMap::from([(Fruit::Banana, 2), (Fruit::Grape, 3)]);
+    /// let err = map.try_map_keys(taste).unwrap_err();
+    /// assert_eq!(err.key(), Taste::Sweet);
+    /// ```
+    pub fn try_map_keys<const L2: usize, F, G>(
+        self,
+        mut g: G,
+    ) -> Result<EnumMap<L2, F, V>, DuplicateKeyError<F>>
+    where
+        F: Enum<L2>,
+        G: FnMut(E) -> F,
+    {
+        EnumMap::from_iter_unique(self.into_iter().map(|(key, value)| (g(key), value)))
+    }
+
+    /// Updates the value of a key using a closure, returning the previous value.
+    ///
+    /// The closure is passed a reference to the current value of the key,
+    /// the returned value replaces the current value which is then returned.
+    /// Returning `None` from `f` removes the key from the map.
+    ///
+    /// Note that `f` receives an `Option<&V>` rather than the owned value:
+    /// the current value stays in the map until `f` returns, so the previous
+    /// value can be handed back to the caller. To transform a value that is
+    /// not [`Clone`] by ownership, [`remove`](Self::remove) it and
+    /// [`insert`](Self::insert) the result, or use [`entry`](Self::entry).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::new();
+    ///
+    /// // Insert a new value.
+    /// assert_eq!(map.update(Fruit::Orange, |v| Some(v.map_or(1, |v| v + 1))), None);
+    /// assert_eq!(map[Fruit::Orange], 1);
+    ///
+    /// // Modify an existing value.
+    /// assert_eq!(map.update(Fruit::Orange, |v| v.map(|v| v + 1)), Some(1));
+    /// assert_eq!(map[Fruit::Orange], 2);
+    ///
+    /// // Remove the value.
+    /// assert_eq!(map.update(Fruit::Orange, |_| None), Some(2));
+    /// assert!(!map.contains_key(Fruit::Orange));
+    /// ```
+    pub fn update<F>(&mut self, key: E, f: F) -> Option<V>
+    where
+        F: FnOnce(Option<&V>) -> Option<V>,
+    {
+        let slot = &mut self.data[E::to_index(key)];
+        let value = f(slot.as_ref());
+        core::mem::replace(slot, value)
+    }
+
+    /// Applies `f` to the value of `key` if it is present, otherwise inserts `default`.
+    ///
+    /// `f` is not called for a newly inserted `default`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut counts = EnumMap::from([(Fruit::Orange, 3)]);
+    ///
+    /// counts.update_or_insert(Fruit::Orange, 1, |count| *count += 1);
+    /// counts.update_or_insert(Fruit::Grape, 1, |count| *count += 1);
+    ///
+    /// assert_eq!(counts, EnumMap::from([(Fruit::Orange, 4), (Fruit::Grape, 1)]));
+    /// ```
+    pub fn update_or_insert<F: FnOnce(&mut V)>(&mut self, key: E, default: V, f: F) {
+        match &mut self.data[E::to_index(key)] {
+            Some(value) => f(value),
+            slot @ None => *slot = Some(default),
+        }
+    }
+
+    /// An iterator visiting all values in order. The iterator element type is `&'a V`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Grape, 2),
+    /// ]);
+    ///
+    /// for value in map.values() {
+    ///     println!("{value:?}");
+    /// }
+    ///
+    /// assert_eq!(map.values().next_back(), Some(&2));
+    /// # let mut iter = map.values();
+    /// # assert!(matches!(iter.next(), Some(1)));
+    /// # assert!(matches!(iter.next(), Some(2)));
+    /// # assert!(iter.next().is_none());
+    /// # let mut iter = map.values();
+    /// # assert!(matches!(iter.next_back(), Some(2)));
+    /// # assert!(matches!(iter.next(), Some(1)));
+    /// # assert!(iter.next_back().is_none());
+    /// ```
+    pub fn values(&self) -> Values<'_, LENGTH, E, V> {
+        Values { inner: self.iter() }
+    }
+
+    /// Returns `true` if both maps contain the same values, regardless of their keys.
+    ///
+    /// The present values are compared as multisets, i.e. each value must occur
+    /// the same amount of times in both maps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let a = EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 2), (Fruit::Grape, 2)]);
+    /// let b = EnumMap::from([(Fruit::Orange, 2), (Fruit::Banana, 1), (Fruit::Grape, 2)]);
+    /// let c = EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 1), (Fruit::Grape, 2)]);
+    ///
+    /// assert!(a.values_eq(&b));
+    /// assert!(!a.values_eq(&c));
+    /// assert!(!a.values_eq(&EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 2)])));
+    /// ```
+    pub fn values_eq(&self, other: &Self) -> bool
+    where
+        V: PartialEq,
+    {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        let mut matched = [false; LENGTH];
+        self.values().all(|value| {
+            let found = other
+                .data
+                .iter()
+                .zip(matched.iter_mut())
+                .find(|(other, matched)| !**matched && other.as_ref() == Some(value));
+
+            match found {
+                Some((_, matched)) => {
+                    *matched = true;
+                    true
+                }
+                None => false,
+            }
+        })
+    }
+
+    /// An iterator visiting the values of all keys in order.
+    /// The iterator element type is `Option<&'a V>`.
+    ///
+    /// Unlike [`EnumMap::values`], keys without a value yield `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Grape, 3),
+    /// ]);
+    ///
+    /// let values: Vec<_> = map.values_full().collect();
+    /// assert_eq!(values, vec![Some(&1), None, Some(&3)]);
+    /// ```
+    pub fn values_full(&self) -> ValuesFull<'_, LENGTH, E, V> {
+        ValuesFull {
+            inner: self.iter_full(),
+        }
+    }
+
+    /// An iterator visiting all values mutably in order. The iterator element type is `&'a mut V`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([
+    ///     (Fruit::Orange, 1),
+    ///     (Fruit::Grape, 2),
+    /// ]);
+    ///
+    /// for value in map.values_mut() {
+    ///     *value += 10;
+    /// }
+    ///
+    /// assert_eq!(map[Fruit::Orange], 11);
+    /// assert_eq!(map[Fruit::Grape], 12);
+    ///
+    /// // Decay values from the highest to the lowest index.
+    /// let mut factor = 1;
+    /// for value in map.values_mut().rev() {
+    ///     *value *= factor;
+    ///     factor *= 10;
+    /// }
+    ///
+    /// assert_eq!(map[Fruit::Orange], 110);
+    /// assert_eq!(map[Fruit::Grape], 12);
+    /// # let mut iter = map.values_mut();
+    /// # assert!(matches!(iter.next(), Some(110)));
+    /// # assert!(matches!(iter.next(), Some(12)));
+    /// # assert!(iter.next().is_none());
+    /// ```
+    pub fn values_mut(&mut self) -> ValuesMut<'_, LENGTH, E, V> {
+        ValuesMut {
+            inner: self.iter_mut(),
+        }
+    }
+
+    /// Creates an empty `EnumMap`, equivalent to [`EnumMap::new`].
+    ///
+    /// Exists for compatibility with generic code written against maps which allocate,
+    /// the requested capacity is ignored. The capacity of the map is always `LENGTH`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let map = EnumMap::<{ Fruit::LENGTH }, Fruit, i32>::with_capacity(100);
+    /// assert!(map.is_empty());
+    /// assert_eq!(map.capacity(), Fruit::LENGTH);
+    /// ```
+    pub fn with_capacity(_capacity: usize) -> Self {
+        Self::new()
+    }
+
+    /// Combines two maps into a map of pairs.
+    ///
+    /// A key is only present in the returned map if it is present in both maps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::EnumMap;
+    ///
+    /// let stock = EnumMap::from([(Fruit::Orange, 10), (Fruit::Banana, 20)]);
+    /// let prices = EnumMap::from([(Fruit::Banana, 1.5), (Fruit::Grape, 3.0)]);
+    ///
+    /// let zipped = stock.zip(prices);
+    /// assert_eq!(zipped, EnumMap::from([(Fruit::Banana, (20, 1.5))]));
+    /// ```
+    pub fn zip<U>(self, other: EnumMap<LENGTH, E, U>) -> EnumMap<LENGTH, E, (V, U)> {
+        self.zip_with(other, |_, v, u| (v, u))
+    }
+
+    /// An iterator visiting all keys present in either map in order, with optional
+    /// references to the values of both maps.
+    /// The iterator element type is `(E, Option<&'a V>, Option<&'a U>)`.
+    ///
+    /// Keys which are present in neither map are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::EnumMap;
+    ///
+    /// let stock = EnumMap::from([(Fruit::Orange, 10), (Fruit::Banana, 20)]);
+    /// let prices = EnumMap::from([(Fruit::Banana, 1.5), (Fruit::Grape, 3.0)]);
+    ///
+    /// let rows: Vec<_> = stock.zip_iter(&prices).collect();
+    /// assert_eq!(
+    ///     rows,
+    ///     vec![
+    ///         (Fruit::Orange, Some(&10), None),
+    ///         (Fruit::Banana, Some(&20), Some(&1.5)),
+    ///         (Fruit::Grape, None, Some(&3.0)),
+    ///     ]
+    /// );
+    /// ```
+    pub fn zip_iter<'a, U>(
+        &'a self,
+        other: &'a EnumMap<LENGTH, E, U>,
+    ) -> ZipIter<'a, LENGTH, E, V, U> {
+        ZipIter {
+            left: self,
+            right: other,
+            index: 0,
+        }
+    }
+
+    /// Combines two maps into a new map using a closure.
+    ///
+    /// The closure is called for every key which is present in both maps,
+    /// all other keys are absent in the returned map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::EnumMap;
+    ///
+    /// let stock = EnumMap::from([(Fruit::Orange, 10), (Fruit::Banana, 20)]);
+    /// let prices = EnumMap::from([(Fruit::Banana, 2), (Fruit::Grape, 3)]);
+    ///
+    /// let value = stock.zip_with(prices, |_, amount, price| amount * price);
+    /// assert_eq!(value, EnumMap::from([(Fruit::Banana, 40)]));
+    /// ```
+    pub fn zip_with<U, W, F>(
+        self,
+        mut other: EnumMap<LENGTH, E, U>,
+        mut f: F,
+    ) -> EnumMap<LENGTH, E, W>
+    where
+        F: FnMut(E, V, U) -> W,
+    {
+        let mut result = EnumMap::new();
+        for (key, value) in self {
+            if let Some(other) = other.remove(key) {
+                result.insert(key, f(key, value, other));
+            }
+        }
+        result
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>> EnumMap<LENGTH, E, usize> {
+    /// Creates an `EnumMap` counting the occurrences of each key in the iterator.
+    ///
+    /// Keys which do not occur in the iterator are absent from the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let basket = vec![Fruit::Orange, Fruit::Grape, Fruit::Orange];
+    /// let counts = EnumMap::count_from_iter(basket);
+    ///
+    /// assert_eq!(counts, EnumMap::from([(Fruit::Orange, 2), (Fruit::Grape, 1)]));
+    /// ```
+    pub fn count_from_iter<I: IntoIterator<Item = E>>(iter: I) -> Self {
+        let mut counts = Self::new();
+        for key in iter {
+            *counts.get_or_insert_default(key) += 1;
+        }
+        counts
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> Default for EnumMap<LENGTH, E, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> AsRef<[Option<V>]> for EnumMap<LENGTH, E, V> {
+    /// Returns the underlying storage as a slice.
+    ///
+    /// The option at index `i` holds the value for the key `E::from_index(i)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// fn present(values: &[Option<i32>]) -> usize {
+    ///     values.iter().flatten().count()
+    /// }
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    /// assert_eq!(present(map.as_ref()), 2);
+    /// ```
+    fn as_ref(&self) -> &[Option<V>] {
+        &self.data
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> AsMut<[Option<V>]> for EnumMap<LENGTH, E, V> {
+    /// Returns the underlying storage as a mutable slice.
+    ///
+    /// The option at index `i` holds the value for the key `E::from_index(i)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// fn reset(values: &mut [Option<i32>]) {
+    ///     values.fill(Some(0));
+    /// }
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1)]);
+    /// reset(map.as_mut());
+    /// assert_eq!(map, EnumMap::from([(Fruit::Orange, 0), (Fruit::Banana, 0), (Fruit::Grape, 0)]));
+    /// ```
+    fn as_mut(&mut self) -> &mut [Option<V>] {
+        &mut self.data
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V, const N: usize> From<[(E, V); N]>
+    for EnumMap<LENGTH, E, V>
+{
+    /// Creates an `EnumMap` from key-value pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{EnumMap, Enum};
+    ///
+    /// let map1 = EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 2)]);
+    /// let map2: EnumMap<{ Fruit::LENGTH }, _, _> = [(Fruit::Orange, 1), (Fruit::Banana, 2)].into();
+    /// assert_eq!(map1, map2);
+    /// ```
+    fn from(value: [(E, V); N]) -> Self {
+        Self::from_iter(value)
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> From<[Option<V>; LENGTH]> for EnumMap<LENGTH, E, V> {
+    /// Creates an enum map from the underlying array representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([None, Some(1), None]);
+    /// assert_eq!(map[Fruit::Banana], 1);
+    /// assert!(map.get(Fruit::Orange).is_none());
+    /// ```
+    fn from(value: [Option<V>; LENGTH]) -> Self {
+        Self::from_array(value)
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> From<EnumMap<LENGTH, E, V>> for [Option<V>; LENGTH] {
+    /// Extracts the underlying array representation from an `EnumMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{EnumMap, Enum};
+    ///
+    /// let map = EnumMap::from([(Fruit::Banana, 1)]);
+    /// assert_eq!(<[_; { Fruit::LENGTH }]>::from(map), [None, Some(1), None]);
+    /// ```
+    fn from(value: EnumMap<LENGTH, E, V>) -> Self {
+        value.data
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V: PartialEq> PartialEq<[Option<V>; LENGTH]>
+    for EnumMap<LENGTH, E, V>
+{
+    /// Compares the map with its underlying array representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    ///
+    /// assert_eq!(map, [Some(1), None, Some(3)]);
+    /// assert_ne!(map, [Some(1), Some(2), Some(3)]);
+    /// ```
+    fn eq(&self, other: &[Option<V>; LENGTH]) -> bool {
+        self.data == *other
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V: PartialEq, const N: usize> PartialEq<[(E, V); N]>
+    for EnumMap<LENGTH, E, V>
+{
+    /// Compares the map with an array of key-value pairs.
+    ///
+    /// The comparison is equivalent to comparing with the map created
+    /// from the array using [`EnumMap::from`], i.e. for duplicate keys the last value is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+    ///
+    /// assert_eq!(map, [(Fruit::Grape, 3), (Fruit::Orange, 1)]);
+    /// assert_eq!(map, [(Fruit::Orange, 0), (Fruit::Grape, 3), (Fruit::Orange, 1)]);
+    /// assert_ne!(map, [(Fruit::Orange, 1)]);
+    /// ```
+    fn eq(&self, other: &[(E, V); N]) -> bool {
+        let mut expected = [None; LENGTH];
+        for (key, value) in other {
+            expected[E::to_index(*key)] = Some(value);
+        }
+        self.data.iter().map(Option::as_ref).eq(expected)
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V: Clone> TryFrom<&[(E, V)]> for EnumMap<LENGTH, E, V> {
+    type Error = DuplicateKeyError<E>;
+
+    /// Creates an `EnumMap` from key-value pairs, rejecting duplicate keys.
+    ///
+    /// Unlike the [`From`] implementation for arrays, which lets later pairs overwrite
+    /// earlier ones, this returns an error for the first key which appears more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let pairs = [(Fruit::Orange, 1), (Fruit::Banana, 2)];
+    /// let map = EnumMap::<{ Fruit::LENGTH }, _, _>::try_from(&pairs[..]).unwrap();
+    /// assert_eq!(map, EnumMap::from(pairs));
+    ///
+    /// let pairs = [(Fruit::Orange, 1), (Fruit::Banana, 2), (Fruit::Orange, 3)];
+    /// let err = EnumMap::<{ Fruit::LENGTH }, _, _>::try_from(&pairs[..]).unwrap_err();
+    /// assert_eq!(err.key(), Fruit::Orange);
+    /// ```
+    fn try_from(value: &[(E, V)]) -> Result<Self, Self::Error> {
+        Self::from_iter_unique(value.iter().map(|(k, v)| (*k, v.clone())))
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> FromIterator<(E, V)> for EnumMap<LENGTH, E, V> {
+    fn from_iter<T: IntoIterator<Item = (E, V)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+/// Inserts all new key-values from the iterator and replaces values with existing
+/// keys with new values returned from the iterator.
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> Extend<(E, V)> for EnumMap<LENGTH, E, V> {
+    #[inline]
+    fn extend<T: IntoIterator<Item = (E, V)>>(&mut self, iter: T) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+/// Indexing panics if the key is absent. The enum is not required to implement
+/// [`Debug`](fmt::Debug), so the panic message names the index of the missing key.
+/// Use [`EnumMap::expect_get`] to panic with a custom message instead.
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> core::ops::Index<E> for EnumMap<LENGTH, E, V> {
+    type Output = V;
+
+    #[track_caller]
+    fn index(&self, index: E) -> &Self::Output {
+        let index = E::to_index(index);
+        match &self.data[index] {
+            Some(value) => value,
+            None => panic!("no entry found for key at index {index}"),
+        }
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> core::ops::IndexMut<E> for EnumMap<LENGTH, E, V> {
+    /// Returns a mutable reference to the value of the key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1)]);
+    /// map[Fruit::Orange] += 1;
+    ///
+    /// assert_eq!(map[Fruit::Orange], 2);
+    /// ```
+    #[track_caller]
+    fn index_mut(&mut self, index: E) -> &mut Self::Output {
+        let index = E::to_index(index);
+        match &mut self.data[index] {
+            Some(value) => value,
+            None => panic!("no entry found for key at index {index}"),
+        }
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> core::ops::Index<&E> for EnumMap<LENGTH, E, V> {
+    type Output = V;
+
+    /// Returns a reference to the value of the borrowed key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let map = EnumMap::from([(Fruit::Orange, 1)]);
+    /// assert_eq!(map[&Fruit::Orange], 1);
+    /// ```
+    #[track_caller]
+    fn index(&self, index: &E) -> &Self::Output {
+        &self[*index]
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> core::ops::IndexMut<&E> for EnumMap<LENGTH, E, V> {
+    /// Returns a mutable reference to the value of the borrowed key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1)]);
+    /// for fruit in &[Fruit::Orange] {
+    ///     map[fruit] *= 10;
+    /// }
+    ///
+    /// assert_eq!(map[&Fruit::Orange], 10);
+    /// ```
+    #[track_caller]
+    fn index_mut(&mut self, index: &E) -> &mut Self::Output {
+        &mut self[*index]
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> IntoIterator for EnumMap<LENGTH, E, V> {
+    type Item = (E, V);
+    type IntoIter = IntoIter<LENGTH, E, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::<LENGTH, E, V>::new(self)
+    }
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> IntoIterator for &'a EnumMap<LENGTH, E, V> {
+    type Item = (E, &'a V);
+    type IntoIter = Iter<'a, LENGTH, E, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> fmt::Debug for EnumMap<LENGTH, E, V>
+where
+    E: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Debug formatting wrapper returned from [`EnumMap::debug_full`].
+pub struct DebugFull<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+    map: &'a EnumMap<LENGTH, E, V>,
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> fmt::Debug for DebugFull<'a, LENGTH, E, V>
+where
+    E: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.map.iter_full()).finish()
+    }
+}
+
+/// Error returned from [`EnumMap::try_get_disjoint_mut`] when a key is requested more than once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisjointError<E> {
+    key: E,
+}
+
+impl<E: Copy> DisjointError<E> {
+    /// Returns the key which was requested more than once.
+    pub fn key(&self) -> E {
+        self.key
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for DisjointError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "key {:?} requested more than once", self.key)
+    }
+}
+
+/// Error returned when a key occurs more than once while constructing an [`EnumMap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DuplicateKeyError<E> {
+    key: E,
+}
+
+impl<E: Copy> DuplicateKeyError<E> {
+    /// Returns the key which occurred more than once.
+    pub fn key(&self) -> E {
+        self.key
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for DuplicateKeyError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "duplicate key {:?}", self.key)
+    }
+}
+
+/// A change of a single key between two maps, returned from [`EnumMap::diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapDiff<V> {
+    /// The key is only present in the new map.
+    Added(V),
+    /// The key is only present in the old map.
+    Removed(V),
+    /// The key is present in both maps with different values, `(old, new)`.
+    Changed(V, V),
+    /// The key is present in both maps with equal values.
+    Unchanged(V),
+}
+
+/// A view into a single entry of an [`EnumMap`], which may either be vacant or occupied.
+///
+/// Returned from [`EnumMap::entry`].
+pub enum Entry<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, LENGTH, E, V>),
+    /// A vacant entry.
+    Vacant(VacantEntry<'a, LENGTH, E, V>),
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Entry<'a, LENGTH, E, V> {
+    /// Returns the key of the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::<3, Fruit, i32>::new();
+    /// assert_eq!(map.entry(Fruit::Banana).key(), Fruit::Banana);
+    /// ```
+    pub fn key(&self) -> E {
+        match self {
+            Self::Occupied(entry) => entry.key(),
+            Self::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Calls `f` with the value if the entry is occupied and returns the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1)]);
+    ///
+    /// map.entry(Fruit::Orange).and_modify(|v| *v += 1).or_insert(10);
+    /// map.entry(Fruit::Grape).and_modify(|v| *v += 1).or_insert(10);
+    ///
+    /// assert_eq!(map, EnumMap::from([(Fruit::Orange, 2), (Fruit::Grape, 10)]));
+    /// ```
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Self::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+
+    /// Inserts `default` if the entry is vacant and returns a mutable reference to the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumMap;
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1)]);
+    ///
+    /// assert_eq!(*map.entry(Fruit::Orange).or_insert(10), 1);
+    /// assert_eq!(*map.entry(Fruit::Grape).or_insert(10), 10);
+    /// ```
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Inserts the result of `f` if the entry is vacant and returns a mutable reference to the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
     /// use enumap::EnumMap;
     ///
-    /// let mut map = EnumMap::from([
-    ///     (Fruit::Orange, 1),
-    ///     (Fruit::Grape, 2),
-    /// ]);
+    /// let mut map = EnumMap::new();
+    /// map.entry(Fruit::Banana).or_insert_with(Vec::new).push(1);
     ///
-    /// for value in map.values() {
-    ///     println!("{value:?}");
-    /// }
-    /// # let mut iter = map.values();
-    /// # assert!(matches!(iter.next(), Some(1)));
-    /// # assert!(matches!(iter.next(), Some(2)));
-    /// # assert!(iter.next().is_none());
+    /// assert_eq!(map, EnumMap::from([(Fruit::Banana, vec![1])]));
     /// ```
-    pub fn values(&self) -> Values<'_, LENGTH, E, V> {
-        Values { inner: self.iter() }
+    pub fn or_insert_with<F: FnOnce() -> V>(self, f: F) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => entry.insert(f()),
+        }
     }
 
-    /// An iterator visiting all values mutably in order. The iterator element type is `&'a mut V`.
+    /// Inserts the result of `f` called with the key if the entry is vacant
+    /// and returns a mutable reference to the value.
     ///
     /// # Examples
     ///
     /// ```
-    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumMap};
+    ///
+    /// let mut map = EnumMap::new();
+    /// assert_eq!(*map.entry(Fruit::Grape).or_insert_with_key(Fruit::to_index), 2);
+    /// ```
+    pub fn or_insert_with_key<F: FnOnce(E) -> V>(self, f: F) -> &'a mut V {
+        match self {
+            Self::Occupied(entry) => entry.into_mut(),
+            Self::Vacant(entry) => {
+                let value = f(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Inserts the default value if the entry is vacant and returns a mutable reference to the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
     /// use enumap::EnumMap;
     ///
-    /// let mut map = EnumMap::from([
-    ///     (Fruit::Orange, 1),
-    ///     (Fruit::Grape, 2),
-    /// ]);
+    /// let mut map = EnumMap::new();
+    /// *map.entry(Fruit::Orange).or_default() += 5;
     ///
-    /// for value in map.values_mut() {
-    ///     *value += 10;
+    /// assert_eq!(map, EnumMap::from([(Fruit::Orange, 5)]));
+    /// ```
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+}
+
+/// A view into an occupied entry of an [`EnumMap`], part of the [`Entry`] enum.
+pub struct OccupiedEntry<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+    key: E,
+    slot: &'a mut Option<V>,
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> OccupiedEntry<'a, LENGTH, E, V> {
+    /// Returns the key of the entry.
+    pub fn key(&self) -> E {
+        self.key
+    }
+
+    /// Returns a reference to the value of the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{map::Entry, EnumMap};
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1)]);
+    ///
+    /// if let Entry::Occupied(entry) = map.entry(Fruit::Orange) {
+    ///     assert_eq!(entry.key(), Fruit::Orange);
+    ///     assert_eq!(entry.get(), &1);
     /// }
+    /// ```
+    pub fn get(&self) -> &V {
+        match self.slot.as_ref() {
+            Some(value) => value,
+            None => unreachable!("occupied entry without a value"),
+        }
+    }
+
+    /// Returns a mutable reference to the value of the entry.
+    ///
+    /// The reference is bound to the entry, use [`OccupiedEntry::into_mut`]
+    /// for a reference which outlives the entry.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!(map[Fruit::Orange], 11);
-    /// assert_eq!(map[Fruit::Grape], 12);
-    /// # let mut iter = map.values_mut();
-    /// # assert!(matches!(iter.next(), Some(11)));
-    /// # assert!(matches!(iter.next(), Some(12)));
-    /// # assert!(iter.next().is_none());
     /// ```
-    pub fn values_mut(&mut self) -> ValuesMut<'_, LENGTH, E, V> {
-        ValuesMut {
-            inner: self.iter_mut(),
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{map::Entry, EnumMap};
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1)]);
+    ///
+    /// if let Entry::Occupied(mut entry) = map.entry(Fruit::Orange) {
+    ///     *entry.get_mut() += 1;
+    ///     *entry.get_mut() += 1;
+    /// }
+    ///
+    /// assert_eq!(map[Fruit::Orange], 3);
+    /// ```
+    pub fn get_mut(&mut self) -> &mut V {
+        match self.slot {
+            Some(value) => value,
+            None => unreachable!("occupied entry without a value"),
         }
     }
-}
 
-impl<const LENGTH: usize, E: Enum<LENGTH>, V> Default for EnumMap<LENGTH, E, V> {
-    fn default() -> Self {
-        Self::new()
+    /// Converts the entry into a mutable reference to its value, bound to the lifetime of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{map::Entry, EnumMap};
+    ///
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1)]);
+    ///
+    /// if let Entry::Occupied(entry) = map.entry(Fruit::Orange) {
+    ///     *entry.into_mut() += 1;
+    /// }
+    ///
+    /// assert_eq!(map[Fruit::Orange], 2);
+    /// ```
+    pub fn into_mut(self) -> &'a mut V {
+        match self.slot {
+            Some(value) => value,
+            None => unreachable!("occupied entry without a value"),
+        }
     }
-}
 
-impl<const LENGTH: usize, E: Enum<LENGTH>, V, const N: usize> From<[(E, V); N]>
-    for EnumMap<LENGTH, E, V>
-{
-    /// Creates an `EnumMap` from key-value pairs.
+    /// Replaces the value of the entry and returns the old value.
     ///
     /// # Examples
     ///
     /// ```
     /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
-    /// use enumap::{EnumMap, Enum};
+    /// use enumap::{map::Entry, EnumMap};
     ///
-    /// let map1 = EnumMap::from([(Fruit::Orange, 1), (Fruit::Banana, 2)]);
-    /// let map2: EnumMap<{ Fruit::LENGTH }, _, _> = [(Fruit::Orange, 1), (Fruit::Banana, 2)].into();
-    /// assert_eq!(map1, map2);
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1)]);
+    ///
+    /// if let Entry::Occupied(mut entry) = map.entry(Fruit::Orange) {
+    ///     assert_eq!(entry.insert(5), 1);
+    /// }
+    ///
+    /// assert_eq!(map[Fruit::Orange], 5);
     /// ```
-    fn from(value: [(E, V); N]) -> Self {
-        Self::from_iter(value)
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
     }
-}
 
-impl<const LENGTH: usize, E: Enum<LENGTH>, V> From<[Option<V>; LENGTH]> for EnumMap<LENGTH, E, V> {
-    /// Creates an enum map from the underlying array representation.
+    /// Removes the entry from the map and returns its value.
     ///
     /// # Examples
     ///
     /// ```
-    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
-    /// use enumap::EnumMap;
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{map::Entry, EnumMap};
     ///
-    /// let map = EnumMap::from([None, Some(1), None]);
-    /// assert_eq!(map[Fruit::Banana], 1);
-    /// assert!(map.get(Fruit::Orange).is_none());
+    /// let mut map = EnumMap::from([(Fruit::Orange, 1)]);
+    ///
+    /// if let Entry::Occupied(entry) = map.entry(Fruit::Orange) {
+    ///     assert_eq!(entry.remove(), 1);
+    /// }
+    ///
+    /// assert!(map.is_empty());
     /// ```
-    fn from(value: [Option<V>; LENGTH]) -> Self {
-        Self {
-            data: value,
-            _enum: PhantomData,
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Removes the entry from the map and returns its key and value.
+    pub fn remove_entry(self) -> (E, V) {
+        match self.slot.take() {
+            Some(value) => (self.key, value),
+            None => unreachable!("occupied entry without a value"),
         }
     }
 }
 
-impl<const LENGTH: usize, E: Enum<LENGTH>, V> From<EnumMap<LENGTH, E, V>> for [Option<V>; LENGTH] {
-    /// Extracts the underlying array representation from an `EnumMap`.
+/// A view into a vacant entry of an [`EnumMap`], part of the [`Entry`] enum.
+pub struct VacantEntry<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+    key: E,
+    slot: &'a mut Option<V>,
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> VacantEntry<'a, LENGTH, E, V> {
+    /// Returns the key of the entry.
+    pub fn key(&self) -> E {
+        self.key
+    }
+
+    /// Inserts the value into the map and returns a mutable reference to it.
     ///
     /// # Examples
     ///
     /// ```
-    /// # enumap::enumap! { enum Fruit { Orange, Banana, Grape, } }
-    /// use enumap::{EnumMap, Enum};
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{map::Entry, EnumMap};
     ///
-    /// let map = EnumMap::from([(Fruit::Banana, 1)]);
-    /// assert_eq!(<[_; { Fruit::LENGTH }]>::from(map), [None, Some(1), None]);
+    /// let mut map = EnumMap::new();
+    ///
+    /// if let Entry::Vacant(entry) = map.entry(Fruit::Grape) {
+    ///     assert_eq!(entry.key(), Fruit::Grape);
+    ///     *entry.insert(1) += 1;
+    /// }
+    ///
+    /// assert_eq!(map, EnumMap::from([(Fruit::Grape, 2)]));
     /// ```
-    fn from(value: EnumMap<LENGTH, E, V>) -> Self {
-        value.data
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.slot.insert(value)
     }
 }
 
-impl<const LENGTH: usize, E: Enum<LENGTH>, V> FromIterator<(E, V)> for EnumMap<LENGTH, E, V> {
-    fn from_iter<T: IntoIterator<Item = (E, V)>>(iter: T) -> Self {
-        let mut map = Self::new();
-        map.extend(iter);
-        map
+/// Iterator returned from [`EnumMap::diff`].
+pub struct Diff<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+    old: &'a EnumMap<LENGTH, E, V>,
+    new: &'a EnumMap<LENGTH, E, V>,
+    index: usize,
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V: PartialEq> Iterator for Diff<'a, LENGTH, E, V> {
+    type Item = (E, MapDiff<&'a V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < LENGTH {
+            let i = self.index;
+            self.index += 1;
+
+            let diff = match (&self.old.data[i], &self.new.data[i]) {
+                (None, None) => continue,
+                (None, Some(new)) => MapDiff::Added(new),
+                (Some(old), None) => MapDiff::Removed(old),
+                (Some(old), Some(new)) if old == new => MapDiff::Unchanged(old),
+                (Some(old), Some(new)) => MapDiff::Changed(old, new),
+            };
+            return Some((key_at::<LENGTH, E>(i), diff));
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(LENGTH - self.index))
     }
 }
 
-/// Inserts all new key-values from the iterator and replaces values with existing
-/// keys with new values returned from the iterator.
-impl<const LENGTH: usize, E: Enum<LENGTH>, V> Extend<(E, V)> for EnumMap<LENGTH, E, V> {
-    #[inline]
-    fn extend<T: IntoIterator<Item = (E, V)>>(&mut self, iter: T) {
-        for (k, v) in iter {
-            self.insert(k, v);
+/// Iterator returned from [`EnumMap::find_keys`].
+pub struct FindKeys<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+    inner: Iter<'a, LENGTH, E, V>,
+    value: &'a V,
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V: PartialEq> Iterator
+    for FindKeys<'a, LENGTH, E, V>
+{
+    type Item = E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.value;
+        self.inner.find(|(_, v)| *v == value).map(|(k, _)| k)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+/// Iterator returned from [`EnumMap::iter`].
+pub struct Iter<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+    index: usize,
+    back: usize,
+    map: &'a EnumMap<LENGTH, E, V>,
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for Iter<'a, LENGTH, E, V> {
+    type Item = (E, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.back {
+            let index = self.index;
+            self.index += 1;
+
+            if let Some(value) = &self.map.data[index] {
+                return Some((key_at::<LENGTH, E>(index), value));
+            }
         }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.back - self.index))
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        while self.index < self.back {
+            let index = self.index;
+            self.index += 1;
+
+            if let Some(value) = &self.map.data[index] {
+                if n == 0 {
+                    return Some((key_at::<LENGTH, E>(index), value));
+                }
+                n -= 1;
+            }
+        }
+
+        None
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> DoubleEndedIterator for Iter<'a, LENGTH, E, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.index < self.back {
+            self.back -= 1;
+            let index = self.back;
+
+            if let Some(value) = &self.map.data[index] {
+                return Some((key_at::<LENGTH, E>(index), value));
+            }
+        }
+
+        None
+    }
+}
+
+/// Iterator returned from [`EnumMap::extract_if`].
+pub struct ExtractIf<'a, const LENGTH: usize, E: Enum<LENGTH>, V, F> {
+    index: usize,
+    map: &'a mut EnumMap<LENGTH, E, V>,
+    pred: F,
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V, F> Iterator for ExtractIf<'a, LENGTH, E, V, F>
+where
+    F: FnMut(E, &mut V) -> bool,
+{
+    type Item = (E, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < LENGTH {
+            let index = self.index;
+            self.index += 1;
+
+            let slot = &mut self.map.data[index];
+            if let Some(value) = slot.as_mut() {
+                let key = key_at::<LENGTH, E>(index);
+                if (self.pred)(key, value) {
+                    return slot.take().map(|value| (key, value));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(LENGTH - self.index))
+    }
+}
+
+/// Iterator returned from [`EnumMap::iter_full`].
+pub struct IterFull<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+    inner: core::iter::Enumerate<core::slice::Iter<'a, Option<V>>>,
+    _enum: PhantomData<E>,
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for IterFull<'a, LENGTH, E, V> {
+    type Item = (E, Option<&'a V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, v) = self.inner.next()?;
+        Some((key_at::<LENGTH, E>(i), v.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Iterator returned from [`EnumMap::iter_indexed`].
+pub struct IterIndexed<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+    inner: core::iter::Enumerate<core::slice::Iter<'a, Option<V>>>,
+    _enum: PhantomData<E>,
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for IterIndexed<'a, LENGTH, E, V> {
+    type Item = (usize, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.find_map(|(i, v)| v.as_ref().map(|v| (i, v)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
     }
 }
 
-impl<const LENGTH: usize, E: Enum<LENGTH>, V> core::ops::Index<E> for EnumMap<LENGTH, E, V> {
-    type Output = V;
-
-    fn index(&self, index: E) -> &Self::Output {
-        self.get(index).expect("no entry found for key")
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> DoubleEndedIterator
+    for IterIndexed<'a, LENGTH, E, V>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner
+            .by_ref()
+            .rev()
+            .find_map(|(i, v)| v.as_ref().map(|v| (i, v)))
     }
 }
 
-impl<const LENGTH: usize, E: Enum<LENGTH>, V> IntoIterator for EnumMap<LENGTH, E, V> {
-    type Item = (E, V);
-    type IntoIter = IntoIter<LENGTH, E, V>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        IntoIter::<LENGTH, E, V>::new(self)
-    }
+/// Iterator returned from [`EnumMap::iter_mut_full`].
+pub struct IterMutFull<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+    inner: core::iter::Enumerate<core::slice::IterMut<'a, Option<V>>>,
+    _enum: PhantomData<E>,
 }
 
-impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> IntoIterator for &'a EnumMap<LENGTH, E, V> {
-    type Item = (E, &'a V);
-    type IntoIter = Iter<'a, LENGTH, E, V>;
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for IterMutFull<'a, LENGTH, E, V> {
+    type Item = (E, &'a mut Option<V>);
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, v) = self.inner.next()?;
+        Some((key_at::<LENGTH, E>(i), v))
     }
-}
 
-impl<const LENGTH: usize, E: Enum<LENGTH>, V> fmt::Debug for EnumMap<LENGTH, E, V>
-where
-    E: fmt::Debug,
-    V: fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_map().entries(self.iter()).finish()
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
     }
 }
 
-/// Iterator returned from [`EnumMap::iter`].
-pub struct Iter<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+/// Iterator returned from [`EnumMap::iter_sorted_by_value`].
+pub struct IterSortedByValue<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+    indices: [usize; LENGTH],
     index: usize,
+    len: usize,
     map: &'a EnumMap<LENGTH, E, V>,
 }
 
-impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for Iter<'a, LENGTH, E, V> {
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for IterSortedByValue<'a, LENGTH, E, V> {
     type Item = (E, &'a V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.index < self.map.data.len() {
-            let index = self.index;
+        while self.index < self.len {
+            let index = self.indices[self.index];
             self.index += 1;
 
             if let Some(value) = &self.map.data[index] {
-                return Some((E::from_index(index)?, value));
+                return Some((key_at::<LENGTH, E>(index), value));
             }
         }
 
         None
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.index;
+        (len, Some(len))
+    }
 }
 
 /// Iterator returned from [`EnumMap::keys`].
@@ -549,6 +3650,42 @@ impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for Keys<'a, LENGTH,
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|(k, _)| k)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(|(k, _)| k)
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.inner.last().map(|(k, _)| k)
+    }
+
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> DoubleEndedIterator for Keys<'a, LENGTH, E, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(k, _)| k)
+    }
+}
+
+/// Iterator returned from [`EnumMap::present_indices`].
+pub struct PresentIndices<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+    inner: core::iter::Enumerate<core::slice::Iter<'a, Option<V>>>,
+    _enum: PhantomData<E>,
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for PresentIndices<'a, LENGTH, E, V> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.find_map(|(i, v)| v.as_ref().map(|_| i))
+    }
 }
 
 /// Iterator returned from [`EnumMap::values`].
@@ -562,6 +3699,45 @@ impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for Values<'a, LENGTH
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|(_, v)| v)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(|(_, v)| v)
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.inner.last().map(|(_, v)| v)
+    }
+
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> DoubleEndedIterator for Values<'a, LENGTH, E, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, v)| v)
+    }
+}
+
+/// Iterator returned from [`EnumMap::values_full`].
+pub struct ValuesFull<'a, const LENGTH: usize, E: Enum<LENGTH>, V> {
+    inner: IterFull<'a, LENGTH, E, V>,
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for ValuesFull<'a, LENGTH, E, V> {
+    type Item = Option<&'a V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, v)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
 }
 
 /// Iterator returned from [`EnumMap::values_mut`].
@@ -575,6 +3751,18 @@ impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for ValuesMut<'a, LEN
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|(_, v)| v)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> DoubleEndedIterator
+    for ValuesMut<'a, LENGTH, E, V>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, v)| v)
+    }
 }
 
 /// Iterator returned from [`EnumMap::into_values`].
@@ -588,6 +3776,58 @@ impl<const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for IntoValues<LENGTH, E,
     fn next(&mut self) -> Option<Self::Item> {
         self.inner.next().map(|(_, v)| v)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(|(_, v)| v)
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.inner.last().map(|(_, v)| v)
+    }
+
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> DoubleEndedIterator for IntoValues<LENGTH, E, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, v)| v)
+    }
+}
+
+/// Iterator returned from [`EnumMap::zip_iter`].
+pub struct ZipIter<'a, const LENGTH: usize, E: Enum<LENGTH>, V, U> {
+    left: &'a EnumMap<LENGTH, E, V>,
+    right: &'a EnumMap<LENGTH, E, U>,
+    index: usize,
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V, U> Iterator for ZipIter<'a, LENGTH, E, V, U> {
+    type Item = (E, Option<&'a V>, Option<&'a U>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < LENGTH {
+            let index = self.index;
+            self.index += 1;
+
+            let left = self.left.data[index].as_ref();
+            let right = self.right.data[index].as_ref();
+            if left.is_some() || right.is_some() {
+                return Some((key_at::<LENGTH, E>(index), left, right));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(LENGTH - self.index))
+    }
 }
 
 /// Iterator returned from [`EnumMap::iter_mut`].
@@ -602,7 +3842,25 @@ impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for IterMut<'a, LENGT
     fn next(&mut self) -> Option<Self::Item> {
         for (i, v) in self.inner.by_ref() {
             if let Some(v) = v.as_mut() {
-                return Some((E::from_index(i)?, v));
+                return Some((key_at::<LENGTH, E>(i), v));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.inner.len()))
+    }
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> DoubleEndedIterator
+    for IterMut<'a, LENGTH, E, V>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some((i, v)) = self.inner.next_back() {
+            if let Some(v) = v.as_mut() {
+                return Some((key_at::<LENGTH, E>(i), v));
             }
         }
 
@@ -613,12 +3871,17 @@ impl<'a, const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for IterMut<'a, LENGT
 /// Iterator returned from [`EnumMap::into_iter`].
 pub struct IntoIter<const LENGTH: usize, E: Enum<LENGTH>, V> {
     index: usize,
+    back: usize,
     map: EnumMap<LENGTH, E, V>,
 }
 
 impl<const LENGTH: usize, E: Enum<LENGTH>, V> IntoIter<LENGTH, E, V> {
     fn new(map: EnumMap<LENGTH, E, V>) -> Self {
-        Self { index: 0, map }
+        Self {
+            index: 0,
+            back: LENGTH,
+            map,
+        }
     }
 }
 
@@ -626,13 +3889,54 @@ impl<const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for IntoIter<LENGTH, E, V
     type Item = (E, V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.index < self.map.data.len() {
+        while self.index < self.back {
+            let index = self.index;
+            self.index += 1;
+
+            let value = core::mem::take(&mut self.map.data[index]);
+            if let Some(value) = value {
+                return Some((key_at::<LENGTH, E>(index), value));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.back - self.index))
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        while self.index < self.back {
             let index = self.index;
             self.index += 1;
 
             let value = core::mem::take(&mut self.map.data[index]);
             if let Some(value) = value {
-                return Some((E::from_index(index)?, value));
+                if n == 0 {
+                    return Some((key_at::<LENGTH, E>(index), value));
+                }
+                n -= 1;
+            }
+        }
+
+        None
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> DoubleEndedIterator for IntoIter<LENGTH, E, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.index < self.back {
+            self.back -= 1;
+            let index = self.back;
+
+            let value = core::mem::take(&mut self.map.data[index]);
+            if let Some(value) = value {
+                return Some((key_at::<LENGTH, E>(index), value));
             }
         }
 
@@ -640,26 +3944,373 @@ impl<const LENGTH: usize, E: Enum<LENGTH>, V> Iterator for IntoIter<LENGTH, E, V
     }
 }
 
+/// Numeric types supporting saturating addition, used by [`EnumMap::sum_values_saturating`].
+///
+/// This trait is sealed and implemented for all primitive integer types.
+pub trait SaturatingAdd: Copy + sealed::Sealed {
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// Adds `other`, saturating at the numeric bounds instead of overflowing.
+    fn saturating_add(self, other: Self) -> Self;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_saturating_add {
+    ($($ty:ty),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+
+            impl SaturatingAdd for $ty {
+                const ZERO: Self = 0;
+
+                fn saturating_add(self, other: Self) -> Self {
+                    <$ty>::saturating_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_add!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Reconstructs the key of an occupied index.
+///
+/// An inconsistent [`Enum`] implementation which does not return a variant for the index
+/// results in a panic instead of silently skipping the entry.
+pub(crate) fn key_at<const LENGTH: usize, E: Enum<LENGTH>>(index: usize) -> E {
+    match E::from_index(index) {
+        Some(key) => key,
+        None => {
+            panic!("invalid `Enum` implementation: `from_index` returned `None` for index {index}")
+        }
+    }
+}
+
 #[cfg(debug_assertions)]
 fn assert_enum_impl<const LENGTH: usize, E>()
 where
     E: Enum<LENGTH>,
 {
-    let ty = core::any::type_name::<E>();
-    for i in 0..LENGTH + 1 {
-        let Some(v) = E::from_index(i) else {
+    if let Err(err) = crate::verify_enum_impl::<LENGTH, E>() {
+        let ty = core::any::type_name::<E>();
+        panic!("Enum {ty} with LENGTH {LENGTH}: {err}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DisjointError, Entry, MapDiff};
+    use crate::{enumap, Enum, EnumMap, EnumSet};
+
+    enumap! {
+        #[derive(Debug, PartialEq)]
+        enum Foo {
+            A,
+            B,
+            C,
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "no entry found for key at index 1")]
+    fn test_index_panic_message() {
+        let map = EnumMap::from([(Foo::A, 1)]);
+        let _ = map[Foo::B];
+    }
+
+    #[test]
+    #[should_panic(expected = "no entry found for key at index 2")]
+    fn test_index_mut_panic_message() {
+        let mut map = EnumMap::from([(Foo::A, 1)]);
+        map[&Foo::C] = 3;
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct Broken(usize);
+
+    impl Enum<2> for Broken {
+        fn from_index(index: usize) -> Option<Self> {
+            (index == 0).then_some(Self(index))
+        }
+
+        fn to_index(value: Self) -> usize {
+            value.0
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "`from_index` returned `None` for index 1")]
+    fn test_iter_inconsistent_enum_impl() {
+        let map = EnumMap::<2, Broken, i32>::from([Some(1), Some(2)]);
+        for _ in &map {}
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct OutOfRange(usize);
+
+    impl Enum<2> for OutOfRange {
+        fn from_index(index: usize) -> Option<Self> {
+            (index < 2).then_some(Self(index))
+        }
+
+        fn to_index(value: Self) -> usize {
+            value.0
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "`to_index` returned 5 which is out of range for length 2")]
+    fn test_from_entries_out_of_range() {
+        let _ =
+            EnumMap::<2, OutOfRange, i32>::from_entries([(OutOfRange(1), 1), (OutOfRange(5), 5)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "multiple keys mapped to the key at index 0")]
+    fn test_map_keys_collision() {
+        let map = EnumMap::from([(Foo::A, 1), (Foo::C, 3)]);
+        let _ = map.map_keys(|_| Foo::A);
+    }
+
+    #[test]
+    #[should_panic(expected = "missing config for C")]
+    fn test_expect_get_panic_message() {
+        let map = EnumMap::from([(Foo::A, 1)]);
+        map.expect_get(Foo::C, "missing config for C");
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = EnumMap::from([(Foo::A, 1), (Foo::B, 2)]);
+        let new = EnumMap::from([(Foo::A, 1), (Foo::B, 3), (Foo::C, 4)]);
+
+        let mut diff = old.diff(&new);
+        assert_eq!(diff.next(), Some((Foo::A, MapDiff::Unchanged(&1))));
+        assert_eq!(diff.next(), Some((Foo::B, MapDiff::Changed(&2, &3))));
+        assert_eq!(diff.next(), Some((Foo::C, MapDiff::Added(&4))));
+        assert_eq!(diff.next(), None);
+
+        let mut diff = new.diff(&old);
+        assert_eq!(diff.next(), Some((Foo::A, MapDiff::Unchanged(&1))));
+        assert_eq!(diff.next(), Some((Foo::B, MapDiff::Changed(&3, &2))));
+        assert_eq!(diff.next(), Some((Foo::C, MapDiff::Removed(&4))));
+        assert_eq!(diff.next(), None);
+
+        let empty = EnumMap::<3, Foo, i32>::new();
+        assert_eq!(empty.diff(&empty).next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_into_sorted_vec() {
+        let map = EnumMap::from([(Foo::C, 1), (Foo::A, 3), (Foo::B, 1)]);
+
+        assert_eq!(
+            map.into_sorted_vec(),
+            [(Foo::A, 3), (Foo::B, 1), (Foo::C, 1)]
+        );
+        assert_eq!(
+            map.into_sorted_vec_by_value(),
+            [(Foo::B, 1), (Foo::C, 1), (Foo::A, 3)]
+        );
+        assert!(EnumMap::<3, Foo, i32>::new().into_sorted_vec().is_empty());
+    }
+
+    #[test]
+    fn test_sum_values_saturating() {
+        let map = EnumMap::from([(Foo::A, 254u8), (Foo::C, 1)]);
+        assert_eq!(map.sum_values_saturating(), 255);
+
+        let map = EnumMap::from([(Foo::A, 254u8), (Foo::B, 1), (Foo::C, 1)]);
+        assert_eq!(map.sum_values_saturating(), u8::MAX);
+
+        let map = EnumMap::from([(Foo::A, i8::MIN), (Foo::B, -1)]);
+        assert_eq!(map.sum_values_saturating(), i8::MIN);
+
+        let map = EnumMap::from([(Foo::A, i8::MAX), (Foo::B, 1), (Foo::C, -1)]);
+        assert_eq!(map.sum_values_saturating(), i8::MAX - 1);
+
+        assert_eq!(EnumMap::<3, Foo, u8>::new().sum_values_saturating(), 0);
+    }
+
+    #[test]
+    fn test_iter_nth_last() {
+        let map = EnumMap::from([(Foo::A, 1), (Foo::C, 3)]);
+        let entries = [(Foo::A, &1), (Foo::C, &3)];
+
+        for n in 0..4 {
+            assert_eq!(map.iter().nth(n), entries.get(n).copied());
             assert_eq!(
-                i, LENGTH,
-                "No variant constructed from index {i} for enum {ty} with LENGTH {LENGTH}",
+                map.into_iter().nth(n),
+                entries.get(n).map(|&(k, &v)| (k, v))
             );
-            return;
+            assert_eq!(map.keys().nth(n), entries.get(n).map(|&(k, _)| k));
+            assert_eq!(map.values().nth(n), entries.get(n).map(|&(_, v)| v));
+        }
+
+        let mut iter = map.iter();
+        assert_eq!(iter.nth(1), Some((Foo::C, &3)));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = map.iter();
+        assert_eq!(iter.nth(5), None);
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(map.iter().last(), entries.last().copied());
+        assert_eq!(map.into_iter().last(), Some((Foo::C, 3)));
+        assert_eq!(map.into_values().last(), Some(3));
+        assert_eq!(map.iter().count(), entries.len());
+
+        let set = EnumSet::from([Foo::B, Foo::C]);
+        let values = [Foo::B, Foo::C];
+        for n in 0..4 {
+            assert_eq!(set.iter().nth(n), values.get(n).copied());
+            assert_eq!(set.into_iter().nth(n), values.get(n).copied());
+        }
+        assert_eq!(set.iter().last(), values.last().copied());
+        assert_eq!(set.into_iter().last(), values.last().copied());
+
+        let mut iter = set.iter();
+        assert_eq!(iter.next(), Some(Foo::B));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.nth(1), None);
+        assert_eq!(iter.len(), 0);
+
+        let mut iter = set.into_iter();
+        assert_eq!(iter.nth(1), Some(Foo::C));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(EnumSet::<3, Foo>::new().iter().last(), None);
+    }
+
+    #[test]
+    fn test_set_iter_len() {
+        let set = EnumSet::from([Foo::A, Foo::B, Foo::C]);
+
+        let mut iter = set.iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(Foo::A));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(Foo::B));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some(Foo::C));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+
+        let mut iter = set.into_iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(Foo::A));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.nth(1), Some(Foo::C));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn test_zip_iter() {
+        let left = EnumMap::from([(Foo::A, 1), (Foo::B, 2)]);
+        let right = EnumMap::from([(Foo::B, 'b'), (Foo::C, 'c')]);
+
+        let mut iter = left.zip_iter(&right);
+        assert_eq!(iter.next(), Some((Foo::A, Some(&1), None)));
+        assert_eq!(iter.next(), Some((Foo::B, Some(&2), Some(&'b'))));
+        assert_eq!(iter.next(), Some((Foo::C, None, Some(&'c'))));
+        assert_eq!(iter.next(), None);
+
+        let left = EnumMap::from([(Foo::A, 1), (Foo::C, 3)]);
+        let right = EnumMap::<3, Foo, char>::new();
+
+        let mut iter = left.zip_iter(&right);
+        assert_eq!(iter.next(), Some((Foo::A, Some(&1), None)));
+        assert_eq!(iter.next(), Some((Foo::C, Some(&3), None)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_entry_remove() {
+        let mut map = EnumMap::from([(Foo::A, 1), (Foo::B, 2)]);
+
+        match map.entry(Foo::B) {
+            Entry::Occupied(entry) => assert_eq!(entry.remove(), 2),
+            Entry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+        assert_eq!(map, EnumMap::from([(Foo::A, 1)]));
+
+        match map.entry(Foo::B) {
+            Entry::Occupied(_) => panic!("expected a vacant entry"),
+            Entry::Vacant(entry) => assert_eq!(entry.key(), Foo::B),
+        }
+
+        match map.entry(Foo::A) {
+            Entry::Occupied(entry) => assert_eq!(entry.remove_entry(), (Foo::A, 1)),
+            Entry::Vacant(_) => panic!("expected an occupied entry"),
+        }
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn test_entry_into_mut() {
+        let mut map = EnumMap::from([(Foo::A, 1)]);
+
+        let value = match map.entry(Foo::A) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(_) => panic!("expected an occupied entry"),
         };
+        *value += 10;
+        assert_eq!(map[Foo::A], 11);
 
-        assert_eq!(
-            i, E::to_index(v),
-            "`to_index` returned different index for variant constructed at index {i} with `from_index`",
-        );
+        let value = match map.entry(Foo::C) {
+            Entry::Occupied(_) => panic!("expected a vacant entry"),
+            Entry::Vacant(entry) => entry.insert(3),
+        };
+        *value *= 2;
+        assert_eq!(map, EnumMap::from([(Foo::A, 11), (Foo::C, 6)]));
+    }
+
+    #[test]
+    fn test_try_get_disjoint_mut() {
+        let mut map = EnumMap::from([(Foo::A, 1), (Foo::C, 3)]);
+
+        let [c, b, a] = map.try_get_disjoint_mut([Foo::C, Foo::B, Foo::A]).unwrap();
+        assert_eq!(b, None);
+        *a.unwrap() += 10;
+        *c.unwrap() += 30;
+        assert_eq!(map, EnumMap::from([(Foo::A, 11), (Foo::C, 33)]));
+
+        let [] = map.try_get_disjoint_mut([]).unwrap();
+    }
+
+    #[test]
+    fn test_try_get_disjoint_mut_collision() {
+        let mut map = EnumMap::from([(Foo::A, 1), (Foo::C, 3)]);
+
+        let err = map
+            .try_get_disjoint_mut([Foo::B, Foo::C, Foo::B])
+            .unwrap_err();
+        assert_eq!(err, DisjointError { key: Foo::B });
+
+        let err = map.try_get_disjoint_mut([Foo::A, Foo::A]).unwrap_err();
+        assert_eq!(err.key(), Foo::A);
     }
 
-    panic!("Enum {ty} yielded more variants from `from_index` than LENGTH ({LENGTH})");
+    #[test]
+    fn test_from_iter_summing() {
+        let map = EnumMap::<3, Foo, u32>::from_iter_summing([
+            (Foo::B, 1),
+            (Foo::A, 10),
+            (Foo::B, 2),
+            (Foo::B, 3),
+        ]);
+        assert_eq!(map, EnumMap::from([(Foo::A, 10), (Foo::B, 6)]));
+
+        let map = EnumMap::<3, Foo, u32>::from_iter_summing([]);
+        assert!(map.is_empty());
+    }
 }
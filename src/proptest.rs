@@ -0,0 +1,89 @@
+//! Property testing support using [`proptest`](::proptest).
+//!
+//! [`EnumMap`] and [`EnumSet`] implement [`Arbitrary`], every key is present with
+//! a probability of `0.5`, values of maps are generated from `V: Arbitrary`.
+//!
+//! ```
+//! # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+//! use enumap::{EnumMap, EnumSet};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn keys_match(map: EnumMap<3, Fruit, u8>) {
+//!         prop_assert_eq!(EnumSet::from(&map).len(), map.len());
+//!     }
+//! }
+//!
+//! keys_match();
+//! ```
+
+use core::fmt;
+
+use ::proptest::{
+    arbitrary::{any, any_with, Arbitrary},
+    array::{uniform, UniformArrayStrategy},
+    bool,
+    option::{self, OptionStrategy},
+    strategy::{Map, Strategy},
+};
+
+use crate::{Enum, EnumMap, EnumSet};
+
+impl<const LENGTH: usize, E, V> Arbitrary for EnumMap<LENGTH, E, V>
+where
+    E: Enum<LENGTH> + fmt::Debug,
+    V: Arbitrary,
+{
+    type Parameters = V::Parameters;
+    type Strategy = Map<
+        UniformArrayStrategy<OptionStrategy<V::Strategy>, [Option<V>; LENGTH]>,
+        fn([Option<V>; LENGTH]) -> Self,
+    >;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        uniform(option::of(any_with::<V>(args))).prop_map(Self::from)
+    }
+}
+
+impl<const LENGTH: usize, E> Arbitrary for EnumSet<LENGTH, E>
+where
+    E: Enum<LENGTH> + fmt::Debug,
+{
+    type Parameters = ();
+    type Strategy =
+        Map<UniformArrayStrategy<bool::Any, [bool; LENGTH]>, fn([bool; LENGTH]) -> Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        uniform(any::<bool>()).prop_map(|present| Self::from_filter(|e| present[E::to_index(e)]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::proptest::prelude::*;
+
+    use crate::{enumap, EnumMap, EnumSet};
+
+    enumap! {
+        #[derive(Debug, PartialEq)]
+        enum Foo {
+            A,
+            B,
+            C,
+            D,
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_map_array_round_trip(map: EnumMap<4, Foo, u32>) {
+            let array: [Option<u32>; 4] = map.into();
+            prop_assert_eq!(EnumMap::from(array), map);
+        }
+
+        #[test]
+        fn test_set_bits_round_trip(set: EnumSet<4, Foo>) {
+            prop_assert_eq!(EnumSet::from_bits(set.to_bits()), Some(set));
+        }
+    }
+}
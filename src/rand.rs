@@ -0,0 +1,107 @@
+//! Random sampling support using [`rand`](::rand).
+//!
+//! Adds [`Enum::random_variant`] and [`EnumSet::random`].
+
+use ::rand::Rng;
+
+use crate::{Enum, EnumSet};
+
+pub(crate) fn random_variant<const LENGTH: usize, E, R>(rng: &mut R) -> E
+where
+    E: Enum<LENGTH>,
+    R: Rng + ?Sized,
+{
+    assert!(
+        LENGTH > 0,
+        "cannot sample a variant of an enum without variants"
+    );
+    crate::map::key_at::<LENGTH, E>(rng.random_range(0..LENGTH))
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
+    /// Creates a random set, every variant is included with a probability of `0.5`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumSet;
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let set = EnumSet::<3, Fruit>::random(&mut rng);
+    ///
+    /// assert!(set.len() <= 3);
+    /// ```
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::from_filter(|_| rng.random())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::rand::{rngs::SmallRng, SeedableRng};
+
+    use crate::{enumap, Enum, EnumSet};
+
+    enumap! {
+        #[derive(Debug, PartialEq)]
+        enum Foo {
+            A,
+            B,
+            C,
+            D,
+            E,
+            F,
+            G,
+            H,
+        }
+    }
+
+    #[test]
+    fn test_random_reproducible() {
+        let mut a = SmallRng::seed_from_u64(7);
+        let mut b = SmallRng::seed_from_u64(7);
+
+        for _ in 0..32 {
+            assert_eq!(Foo::random_variant(&mut a), Foo::random_variant(&mut b));
+            assert_eq!(
+                EnumSet::<8, Foo>::random(&mut a),
+                EnumSet::<8, Foo>::random(&mut b)
+            );
+        }
+    }
+
+    #[test]
+    fn test_random_covers_all() {
+        let mut rng = SmallRng::seed_from_u64(0);
+
+        let variants: EnumSet<8, Foo> = (0..256).map(|_| Foo::random_variant(&mut rng)).collect();
+        assert!(variants.is_full());
+
+        let union = (0..256).fold(EnumSet::<8, Foo>::new(), |acc, _| {
+            &acc | &EnumSet::random(&mut rng)
+        });
+        assert!(union.is_full());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot sample a variant of an enum without variants")]
+    fn test_random_variant_empty() {
+        #[derive(Debug, Clone, Copy)]
+        enum Empty {}
+
+        impl Enum<0> for Empty {
+            fn from_index(_: usize) -> Option<Self> {
+                None
+            }
+
+            fn to_index(value: Self) -> usize {
+                match value {}
+            }
+        }
+
+        let mut rng = SmallRng::seed_from_u64(0);
+        Empty::random_variant(&mut rng);
+    }
+}
@@ -0,0 +1,191 @@
+//! Zero-copy archival support using `rkyv`.
+//!
+//! [`EnumMap`] is archived as [`ArchivedEnumMap`] and [`EnumSet`] as [`ArchivedEnumSet`].
+//! Both keep the array layout of the original, values can be accessed
+//! by key directly from the archived bytes.
+
+use core::marker::PhantomData;
+
+use ::rkyv::{
+    bytecheck::CheckBytes, munge::munge, option::ArchivedOption, rancor::Fallible, Archive,
+    Deserialize, Place, Portable, Serialize,
+};
+
+use crate::{Enum, EnumMap, EnumSet};
+
+/// An archived [`EnumMap`].
+#[derive(CheckBytes, Portable)]
+#[bytecheck(crate = ::rkyv::bytecheck)]
+#[repr(transparent)]
+pub struct ArchivedEnumMap<const LENGTH: usize, E, V> {
+    data: [ArchivedOption<V>; LENGTH],
+    _enum: PhantomData<E>,
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> ArchivedEnumMap<LENGTH, E, V> {
+    /// Returns a slice of the underlying archived array.
+    pub fn as_slice(&self) -> &[ArchivedOption<V>; LENGTH] {
+        &self.data
+    }
+
+    /// Returns `true` if the archived map contains a value for the specified key.
+    pub fn contains_key(&self, key: E) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns a reference to the archived value for the corresponding key.
+    pub fn get(&self, key: E) -> Option<&V> {
+        self.data[E::to_index(key)].as_ref()
+    }
+
+    /// Returns true if the archived map contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.data.iter().all(ArchivedOption::is_none)
+    }
+
+    /// Returns the number of elements in the archived map.
+    pub fn len(&self) -> usize {
+        self.data.iter().filter(|v| v.is_some()).count()
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V: Archive> Archive for EnumMap<LENGTH, E, V> {
+    type Archived = ArchivedEnumMap<LENGTH, E, V::Archived>;
+    type Resolver = [Option<V::Resolver>; LENGTH];
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedEnumMap { data, _enum: _ } = out);
+        self.as_slice().resolve(resolver, data);
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V, S> Serialize<S> for EnumMap<LENGTH, E, V>
+where
+    V: Serialize<S>,
+    S: Fallible + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, V, D> Deserialize<EnumMap<LENGTH, E, V>, D>
+    for ArchivedEnumMap<LENGTH, E, V::Archived>
+where
+    V: Archive,
+    V::Archived: Deserialize<V, D>,
+    D: Fallible + ?Sized,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<EnumMap<LENGTH, E, V>, D::Error> {
+        let data: [Option<V>; LENGTH] = self.data.deserialize(deserializer)?;
+        Ok(EnumMap::from(data))
+    }
+}
+
+/// An archived [`EnumSet`].
+#[derive(CheckBytes, Portable)]
+#[bytecheck(crate = ::rkyv::bytecheck)]
+#[repr(transparent)]
+pub struct ArchivedEnumSet<const LENGTH: usize, E>(ArchivedEnumMap<LENGTH, E, ()>);
+
+impl<const LENGTH: usize, E: Enum<LENGTH>> ArchivedEnumSet<LENGTH, E> {
+    /// Returns true if the archived set contains a value.
+    pub fn contains(&self, value: E) -> bool {
+        self.0.contains_key(value)
+    }
+
+    /// Returns true if the archived set contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of elements in the archived set.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>> Archive for EnumSet<LENGTH, E> {
+    type Archived = ArchivedEnumSet<LENGTH, E>;
+    type Resolver = [Option<()>; LENGTH];
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedEnumSet(map) = out);
+        self.0.resolve(resolver, map);
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, S> Serialize<S> for EnumSet<LENGTH, E>
+where
+    S: Fallible + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>, D> Deserialize<EnumSet<LENGTH, E>, D>
+    for ArchivedEnumSet<LENGTH, E>
+where
+    D: Fallible + ?Sized,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<EnumSet<LENGTH, E>, D::Error> {
+        Ok(EnumSet(self.0.deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::{rancor::Error, Archived};
+
+    use super::{ArchivedEnumMap, ArchivedEnumSet};
+    use crate::{enumap, Enum, EnumMap, EnumSet};
+
+    enumap! {
+        #[derive(Debug, PartialEq, Eq)]
+        enum Foo {
+            A,
+            B,
+            C,
+            D,
+        }
+    }
+
+    #[test]
+    fn test_enum_map_access() {
+        let map = EnumMap::from([(Foo::C, 3u32), (Foo::A, 1)]);
+
+        let bytes = rkyv::to_bytes::<Error>(&map).unwrap();
+        let archived =
+            rkyv::access::<ArchivedEnumMap<{ Foo::LENGTH }, Foo, Archived<u32>>, Error>(&bytes)
+                .unwrap();
+
+        assert_eq!(archived.get(Foo::A).map(|v| v.to_native()), Some(1));
+        assert_eq!(archived.get(Foo::B), None);
+        assert_eq!(archived.get(Foo::C).map(|v| v.to_native()), Some(3));
+        assert_eq!(archived.len(), 2);
+    }
+
+    #[test]
+    fn test_enum_map_round_trip() {
+        let map = EnumMap::from([(Foo::D, 4u32), (Foo::B, 2)]);
+
+        let bytes = rkyv::to_bytes::<Error>(&map).unwrap();
+        let m = rkyv::from_bytes::<EnumMap<{ Foo::LENGTH }, Foo, u32>, Error>(&bytes).unwrap();
+        assert_eq!(m, map);
+    }
+
+    #[test]
+    fn test_enum_set_round_trip() {
+        let set = EnumSet::from([Foo::D, Foo::A]);
+
+        let bytes = rkyv::to_bytes::<Error>(&set).unwrap();
+        let archived =
+            rkyv::access::<ArchivedEnumSet<{ Foo::LENGTH }, Foo>, Error>(&bytes).unwrap();
+        assert!(archived.contains(Foo::A));
+        assert!(!archived.contains(Foo::B));
+
+        let s = rkyv::from_bytes::<EnumSet<{ Foo::LENGTH }, Foo>, Error>(&bytes).unwrap();
+        assert_eq!(s, set);
+    }
+}
@@ -1,3 +1,9 @@
+//! [`serde`] support for [`EnumMap`] and [`EnumSet`].
+//!
+//! By default an [`EnumMap`] is represented as a map and an [`EnumSet`] as a sequence.
+//! The modules in here provide alternative representations,
+//! to be used with `#[serde(with = "...")]`.
+
 use core::marker::PhantomData;
 
 use serde::{
@@ -115,6 +121,462 @@ where
     }
 }
 
+/// Strict [`EnumSet`] representation which rejects duplicate elements.
+///
+/// The set is serialized like the default representation,
+/// but deserialization fails if the same variant appears more than once.
+///
+/// ```
+/// use enumap::{Enum, EnumSet};
+/// use serde::Deserialize;
+///
+/// enumap::enumap! {
+///     #[derive(Debug, PartialEq, Deserialize)]
+///     #[serde(rename_all = "lowercase")]
+///     enum Fruit {
+///         Orange,
+///         Banana,
+///         Grape,
+///     }
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(with = "enumap::serde::set_strict")]
+///     fruits: EnumSet<{ Fruit::LENGTH }, Fruit>,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"fruits":["orange","grape"]}"#).unwrap();
+/// assert_eq!(config.fruits, EnumSet::from([Fruit::Orange, Fruit::Grape]));
+///
+/// assert!(serde_json::from_str::<Config>(r#"{"fruits":["orange","orange"]}"#).is_err());
+/// ```
+pub mod set_strict {
+    use core::marker::PhantomData;
+
+    use serde::{de, Deserialize, Serialize};
+
+    use crate::{Enum, EnumSet};
+
+    /// Serializes the set as a sequence, same as the default representation.
+    pub fn serialize<const LENGTH: usize, E, S>(
+        set: &EnumSet<LENGTH, E>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        E: Enum<LENGTH> + Serialize,
+        S: serde::Serializer,
+    {
+        set.serialize(serializer)
+    }
+
+    /// Deserializes the set from a sequence, failing on duplicate elements.
+    pub fn deserialize<'de, const LENGTH: usize, E, D>(
+        deserializer: D,
+    ) -> Result<EnumSet<LENGTH, E>, D::Error>
+    where
+        E: Enum<LENGTH> + Deserialize<'de>,
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor<const LENGTH: usize, E: Enum<LENGTH>>(PhantomData<EnumSet<LENGTH, E>>);
+
+        impl<'de, const LENGTH: usize, E: Enum<LENGTH>> de::Visitor<'de> for Visitor<LENGTH, E>
+        where
+            E: Deserialize<'de>,
+        {
+            type Value = EnumSet<LENGTH, E>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("an array without duplicates")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let mut result = EnumSet::new();
+                while let Some(value) = seq.next_element::<E>()? {
+                    let index = E::to_index(value);
+                    if !result.insert(value) {
+                        return Err(de::Error::custom(format_args!(
+                            "duplicate element at index {index}"
+                        )));
+                    }
+                }
+                Ok(result)
+            }
+        }
+
+        deserializer.deserialize_seq(Visitor(PhantomData))
+    }
+}
+
+/// Full [`EnumMap`] representation which includes absent entries.
+///
+/// The map is serialized with an entry for every key, absent values are serialized as `None`,
+/// e.g. `null` in JSON. When deserializing, `None` values and missing keys are absent.
+///
+/// ```
+/// use enumap::{Enum, EnumMap};
+/// use serde::{Deserialize, Serialize};
+///
+/// enumap::enumap! {
+///     #[derive(Debug, PartialEq, Deserialize, Serialize)]
+///     #[serde(rename_all = "lowercase")]
+///     enum Fruit {
+///         Orange,
+///         Banana,
+///         Grape,
+///     }
+/// }
+///
+/// #[derive(Debug, PartialEq, Deserialize, Serialize)]
+/// struct Config {
+///     #[serde(with = "enumap::serde::map_full")]
+///     stock: EnumMap<{ Fruit::LENGTH }, Fruit, u32>,
+/// }
+///
+/// let config = Config {
+///     stock: EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]),
+/// };
+///
+/// let json = serde_json::to_string(&config).unwrap();
+/// assert_eq!(json, r#"{"stock":{"orange":1,"banana":null,"grape":3}}"#);
+/// assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+/// ```
+pub mod map_full {
+    use core::marker::PhantomData;
+
+    use serde::{de, ser::SerializeMap, Deserialize, Serialize};
+
+    use crate::{Enum, EnumMap};
+
+    /// Serializes the map with an entry for every key.
+    pub fn serialize<const LENGTH: usize, E, V, S>(
+        map: &EnumMap<LENGTH, E, V>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        E: Enum<LENGTH> + Serialize,
+        V: Serialize,
+        S: serde::Serializer,
+    {
+        let mut s = serializer.serialize_map(Some(LENGTH))?;
+        for (key, value) in map.iter_full() {
+            s.serialize_entry(&key, &value)?;
+        }
+        s.end()
+    }
+
+    /// Deserializes the map from a map, treating `None` values as absent.
+    pub fn deserialize<'de, const LENGTH: usize, E, V, D>(
+        deserializer: D,
+    ) -> Result<EnumMap<LENGTH, E, V>, D::Error>
+    where
+        E: Enum<LENGTH> + Deserialize<'de>,
+        V: Deserialize<'de>,
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor<const LENGTH: usize, E: Enum<LENGTH>, V>(PhantomData<EnumMap<LENGTH, E, V>>);
+
+        impl<'de, const LENGTH: usize, E: Enum<LENGTH>, V> de::Visitor<'de> for Visitor<LENGTH, E, V>
+        where
+            E: Deserialize<'de>,
+            V: Deserialize<'de>,
+        {
+            type Value = EnumMap<LENGTH, E, V>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a map")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let mut result = EnumMap::new();
+                while let Some((key, value)) = map.next_entry::<E, Option<V>>()? {
+                    match value {
+                        Some(value) => result.insert(key, value),
+                        None => result.remove(key),
+                    };
+                }
+                Ok(result)
+            }
+        }
+
+        deserializer.deserialize_map(Visitor(PhantomData))
+    }
+}
+
+/// Lenient [`EnumMap`] representation which skips unknown keys.
+///
+/// The map is serialized like the default representation. When deserializing,
+/// entries with keys which cannot be deserialized into the enum are skipped instead of
+/// failing, which is useful for forward compatible configurations.
+///
+/// Keys are required to be strings, like the keys of a JSON object.
+///
+/// ```
+/// use enumap::{Enum, EnumMap};
+/// use serde::Deserialize;
+///
+/// enumap::enumap! {
+///     #[derive(Debug, PartialEq, Deserialize)]
+///     #[serde(rename_all = "lowercase")]
+///     enum Fruit {
+///         Orange,
+///         Banana,
+///         Grape,
+///     }
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(with = "enumap::serde::map_lenient")]
+///     stock: EnumMap<{ Fruit::LENGTH }, Fruit, u32>,
+/// }
+///
+/// let config: Config =
+///     serde_json::from_str(r#"{"stock":{"orange":1,"apple":{"nested":true},"grape":3}}"#).unwrap();
+/// assert_eq!(config.stock, EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]));
+/// ```
+pub mod map_lenient {
+    use core::marker::PhantomData;
+
+    use serde::{
+        de::{self, value::StrDeserializer, IgnoredAny},
+        Deserialize, Serialize,
+    };
+
+    use crate::{Enum, EnumMap};
+
+    /// Serializes the map as a map, same as the default representation.
+    pub fn serialize<const LENGTH: usize, E, V, S>(
+        map: &EnumMap<LENGTH, E, V>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        E: Enum<LENGTH> + Serialize,
+        V: Serialize,
+        S: serde::Serializer,
+    {
+        map.serialize(serializer)
+    }
+
+    /// Deserializes the map from a map, skipping entries with unknown keys.
+    pub fn deserialize<'de, const LENGTH: usize, E, V, D>(
+        deserializer: D,
+    ) -> Result<EnumMap<LENGTH, E, V>, D::Error>
+    where
+        E: Enum<LENGTH> + Deserialize<'de>,
+        V: Deserialize<'de>,
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor<const LENGTH: usize, E: Enum<LENGTH>, V>(PhantomData<EnumMap<LENGTH, E, V>>);
+
+        impl<'de, const LENGTH: usize, E: Enum<LENGTH>, V> de::Visitor<'de> for Visitor<LENGTH, E, V>
+        where
+            E: Deserialize<'de>,
+            V: Deserialize<'de>,
+        {
+            type Value = EnumMap<LENGTH, E, V>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a map")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let mut result = EnumMap::new();
+                while let Some(key) = map.next_key::<LenientKey<E>>()? {
+                    match key.0 {
+                        Some(key) => {
+                            result.insert(key, map.next_value()?);
+                        }
+                        None => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(result)
+            }
+        }
+
+        deserializer.deserialize_map(Visitor(PhantomData))
+    }
+
+    /// A key which is `None` if it does not match any variant of the enum.
+    struct LenientKey<E>(Option<E>);
+
+    impl<'de, E: Deserialize<'de>> Deserialize<'de> for LenientKey<E> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            struct Visitor<E>(PhantomData<E>);
+
+            impl<'de, E: Deserialize<'de>> de::Visitor<'de> for Visitor<E> {
+                type Value = LenientKey<E>;
+
+                fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    formatter.write_str("a string key")
+                }
+
+                fn visit_str<Err>(self, v: &str) -> Result<Self::Value, Err>
+                where
+                    Err: de::Error,
+                {
+                    let key = E::deserialize(StrDeserializer::<Err>::new(v)).ok();
+                    Ok(LenientKey(key))
+                }
+            }
+
+            deserializer.deserialize_str(Visitor(PhantomData))
+        }
+    }
+}
+
+/// [`EnumMap`] representation keyed by the index of the variant.
+///
+/// The map is serialized as a map from the stringified index, see [`Enum::to_index`],
+/// to the value. Neither serializing nor deserializing require the enum to implement
+/// [`Serialize`] or [`Deserialize`].
+/// Indices outside of the enum's range are rejected when deserializing.
+///
+/// ```
+/// use enumap::{Enum, EnumMap};
+/// use serde::{Deserialize, Serialize};
+///
+/// enumap::enumap! {
+///     #[derive(Debug, PartialEq)]
+///     enum Fruit {
+///         Orange,
+///         Banana,
+///         Grape,
+///     }
+/// }
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "enumap::serde::map_index_keys")]
+///     stock: EnumMap<{ Fruit::LENGTH }, Fruit, u32>,
+/// }
+///
+/// let config = Config {
+///     stock: EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]),
+/// };
+///
+/// let json = serde_json::to_string(&config).unwrap();
+/// assert_eq!(json, r#"{"stock":{"0":1,"2":3}}"#);
+/// assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+/// ```
+pub mod map_index_keys {
+    use core::marker::PhantomData;
+
+    use serde::{de, ser::SerializeMap, Deserialize, Serialize};
+
+    use crate::{Enum, EnumMap};
+
+    /// Serializes the map as a map from stringified indices to values.
+    pub fn serialize<const LENGTH: usize, E, V, S>(
+        map: &EnumMap<LENGTH, E, V>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        E: Enum<LENGTH>,
+        V: Serialize,
+        S: serde::Serializer,
+    {
+        let mut s = serializer.serialize_map(Some(map.len()))?;
+        for (index, value) in map.iter_indexed() {
+            s.serialize_entry(&IndexKey::<LENGTH>(index), value)?;
+        }
+        s.end()
+    }
+
+    /// Deserializes the map from a map of stringified indices to values.
+    pub fn deserialize<'de, const LENGTH: usize, E, V, D>(
+        deserializer: D,
+    ) -> Result<EnumMap<LENGTH, E, V>, D::Error>
+    where
+        E: Enum<LENGTH>,
+        V: Deserialize<'de>,
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor<const LENGTH: usize, E: Enum<LENGTH>, V>(PhantomData<EnumMap<LENGTH, E, V>>);
+
+        impl<'de, const LENGTH: usize, E: Enum<LENGTH>, V> de::Visitor<'de> for Visitor<LENGTH, E, V>
+        where
+            V: Deserialize<'de>,
+        {
+            type Value = EnumMap<LENGTH, E, V>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a map")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let mut result = EnumMap::new();
+                while let Some((IndexKey(index), value)) =
+                    map.next_entry::<IndexKey<LENGTH>, V>()?
+                {
+                    result.as_mut_slice()[index] = Some(value);
+                }
+                Ok(result)
+            }
+        }
+
+        deserializer.deserialize_map(Visitor(PhantomData))
+    }
+
+    /// An index in the range `0..LENGTH`, represented as a string.
+    struct IndexKey<const LENGTH: usize>(usize);
+
+    impl<const LENGTH: usize> Serialize for IndexKey<LENGTH> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serializer.collect_str(&self.0)
+        }
+    }
+
+    impl<'de, const LENGTH: usize> Deserialize<'de> for IndexKey<LENGTH> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            struct Visitor<const LENGTH: usize>;
+
+            impl<'de, const LENGTH: usize> de::Visitor<'de> for Visitor<LENGTH> {
+                type Value = IndexKey<LENGTH>;
+
+                fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    write!(formatter, "a string index below {LENGTH}")
+                }
+
+                fn visit_str<Err>(self, v: &str) -> Result<Self::Value, Err>
+                where
+                    Err: de::Error,
+                {
+                    match v.parse::<usize>() {
+                        Ok(index) if index < LENGTH => Ok(IndexKey(index)),
+                        _ => Err(Err::invalid_value(de::Unexpected::Str(v), &self)),
+                    }
+                }
+            }
+
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde::{Deserialize, Serialize};
@@ -188,4 +650,106 @@ mod tests {
         let m: EnumSet<{ Foo::LENGTH }, Foo> = serde_json::from_str(r#"[]"#).unwrap();
         assert_eq!(m, EnumSet::new());
     }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct StrictSet {
+        #[serde(with = "super::set_strict")]
+        set: EnumSet<{ Foo::LENGTH }, Foo>,
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct FullMap {
+        #[serde(with = "super::map_full")]
+        map: EnumMap<{ Foo::LENGTH }, Foo, i32>,
+    }
+
+    #[test]
+    fn test_enum_map_full() {
+        let m = FullMap {
+            map: EnumMap::from([(Foo::B, 2), (Foo::D, 4)]),
+        };
+
+        let s = serde_json::to_string(&m).unwrap();
+        assert_eq!(s, r#"{"map":{"a":null,"b":2,"c":null,"d":4}}"#);
+
+        let m: FullMap = serde_json::from_str(&s).unwrap();
+        assert_eq!(m.map, EnumMap::from([(Foo::B, 2), (Foo::D, 4)]));
+    }
+
+    #[test]
+    fn test_enum_map_full_missing_keys() {
+        let m: FullMap = serde_json::from_str(r#"{"map":{"a":1,"b":null}}"#).unwrap();
+        assert_eq!(m.map, EnumMap::from([(Foo::A, 1)]));
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct LenientMap {
+        #[serde(with = "super::map_lenient")]
+        map: EnumMap<{ Foo::LENGTH }, Foo, i32>,
+    }
+
+    #[test]
+    fn test_enum_map_lenient() {
+        let m: LenientMap =
+            serde_json::from_str(r#"{"map":{"a":1,"x":[1,{"y":2}],"c":3,"z":null}}"#).unwrap();
+        assert_eq!(m.map, EnumMap::from([(Foo::A, 1), (Foo::C, 3)]));
+
+        let s = serde_json::to_string(&m).unwrap();
+        assert_eq!(s, r#"{"map":{"a":1,"c":3}}"#);
+    }
+
+    #[test]
+    fn test_enum_map_lenient_invalid_value() {
+        let r = serde_json::from_str::<LenientMap>(r#"{"map":{"a":"1"}}"#);
+        assert!(r.is_err());
+    }
+
+    #[test]
+    fn test_enum_set_strict() {
+        let s: StrictSet = serde_json::from_str(r#"{"set":["c","a"]}"#).unwrap();
+        assert_eq!(s.set, EnumSet::from([Foo::A, Foo::C]));
+
+        let s = serde_json::to_string(&s).unwrap();
+        assert_eq!(s, r#"{"set":["a","c"]}"#);
+    }
+
+    #[test]
+    fn test_enum_set_strict_duplicate() {
+        let r = serde_json::from_str::<StrictSet>(r#"{"set":["a","b","a"]}"#);
+        assert!(r.is_err());
+    }
+
+    #[derive(Debug, Deserialize, Serialize)]
+    struct IndexKeysMap {
+        #[serde(with = "super::map_index_keys")]
+        map: EnumMap<{ Foo::LENGTH }, Foo, i32>,
+    }
+
+    #[test]
+    fn test_enum_map_index_keys() {
+        let m = IndexKeysMap {
+            map: EnumMap::from([(Foo::A, 1), (Foo::D, 4)]),
+        };
+
+        let s = serde_json::to_string(&m).unwrap();
+        assert_eq!(s, r#"{"map":{"0":1,"3":4}}"#);
+
+        let m: IndexKeysMap = serde_json::from_str(&s).unwrap();
+        assert_eq!(m.map, EnumMap::from([(Foo::A, 1), (Foo::D, 4)]));
+
+        let m: IndexKeysMap = serde_json::from_str(r#"{"map":{}}"#).unwrap();
+        assert_eq!(m.map, EnumMap::new());
+    }
+
+    #[test]
+    fn test_enum_map_index_keys_invalid() {
+        let r = serde_json::from_str::<IndexKeysMap>(r#"{"map":{"4":1}}"#);
+        assert!(r.is_err());
+
+        let r = serde_json::from_str::<IndexKeysMap>(r#"{"map":{"a":1}}"#);
+        assert!(r.is_err());
+
+        let r = serde_json::from_str::<IndexKeysMap>(r#"{"map":{"-1":1}}"#);
+        assert!(r.is_err());
+    }
 }
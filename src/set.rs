@@ -6,9 +6,15 @@ use crate::{map, Enum, EnumMap};
 
 /// A set implemented as a [`EnumMap`] where the value is `()`.
 #[derive(Clone, Copy, PartialEq, Eq)]
-pub struct EnumSet<const LENGTH: usize, E: Enum<LENGTH>>(EnumMap<LENGTH, E, ()>);
+pub struct EnumSet<const LENGTH: usize, E: Enum<LENGTH>>(pub(crate) EnumMap<LENGTH, E, ()>);
 
 impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
+    /// Compile time assertion that all elements of the set can be represented as bits of a `u128`.
+    const ASSERT_BITS: () = assert!(
+        LENGTH <= u128::BITS as usize,
+        "`EnumSet` with more than 128 elements cannot be represented as bits"
+    );
+
     /// Creates an empty `EnumSet`.
     ///
     /// With `debug_assertions` enabled, the constructor verifies the implementation
@@ -17,6 +23,23 @@ impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
         Self(EnumMap::new())
     }
 
+    /// Creates an `EnumSet` containing every variant of the enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumSet};
+    ///
+    /// let set = EnumSet::<{ Fruit::LENGTH }, Fruit>::all();
+    ///
+    /// assert_eq!(set.len(), Fruit::LENGTH);
+    /// assert!(set.contains(Fruit::Banana));
+    /// ```
+    pub const fn all() -> Self {
+        Self(EnumMap::from_array([Some(()); LENGTH]))
+    }
+
     /// Clears the set, removing all values.
     ///
     /// # Examples
@@ -31,7 +54,6 @@ impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
     /// a.clear();
     /// assert!(a.is_empty());
     /// ```
-
     pub fn clear(&mut self) {
         self.0.clear()
     }
@@ -54,6 +76,29 @@ impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
         self.0.contains_key(value)
     }
 
+    /// Returns `true` if the set contains the value with the given index.
+    ///
+    /// Unlike [`EnumSet::contains`] this can be used in a const context,
+    /// an out of range index is never contained.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumSet};
+    ///
+    /// const ENABLED: EnumSet<{ Fruit::LENGTH }, Fruit> =
+    ///     EnumSet::from_indices(&[Fruit::Orange as usize]);
+    /// const ORANGE_ENABLED: bool = ENABLED.contains_index(Fruit::Orange as usize);
+    ///
+    /// assert!(ORANGE_ENABLED);
+    /// assert!(!ENABLED.contains_index(Fruit::Grape as usize));
+    /// assert!(!ENABLED.contains_index(Fruit::LENGTH));
+    /// ```
+    pub const fn contains_index(&self, index: usize) -> bool {
+        index < LENGTH && self.0.as_slice()[index].is_some()
+    }
+
     /// Visits the values representing the difference, i.e., the values that are in self but not in other.
     ///
     /// # Examples
@@ -87,6 +132,165 @@ impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
         }
     }
 
+    /// Returns the number of values in the difference
+    /// without visiting them, see [`EnumSet::difference`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::{Enum, EnumSet};
+    ///
+    /// let a = EnumSet::from([Fruit::Orange, Fruit::Banana, Fruit::Apple]);
+    /// let b = EnumSet::from([Fruit::Orange, Fruit::Grape]);
+    ///
+    /// assert_eq!(a.difference_count(&b), a.difference(&b).count());
+    /// assert_eq!(a.difference_count(&b), 2);
+    /// ```
+    pub fn difference_count(&self, other: &EnumSet<LENGTH, E>) -> usize {
+        self.count_with(other, |a, b| a && !b)
+    }
+
+    /// Creates a set containing every variant for which `f` returns `true`.
+    ///
+    /// Variants are passed to `f` in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::{Enum, EnumSet};
+    ///
+    /// let even = EnumSet::from_filter(|fruit| Fruit::to_index(fruit) % 2 == 0);
+    /// assert_eq!(even, EnumSet::from([Fruit::Orange, Fruit::Grape]));
+    /// ```
+    pub fn from_filter<F: FnMut(E) -> bool>(mut f: F) -> Self {
+        let mut set = Self::new();
+        for index in 0..LENGTH {
+            if let Some(value) = E::from_index(index) {
+                if f(value) {
+                    set.insert(value);
+                }
+            }
+        }
+        set
+    }
+
+    /// Creates an `EnumSet` from a bitmask, where bit `i` represents the element with index `i`.
+    ///
+    /// Returns `None` if a bit outside of `0..LENGTH` is set,
+    /// see [`EnumSet::from_bits_truncate`] to ignore these bits instead.
+    /// Sets with more than 128 elements cannot be converted, which is a compile time error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumSet};
+    ///
+    /// let set = EnumSet::<{ Fruit::LENGTH }, Fruit>::from_bits(0b101).unwrap();
+    /// assert_eq!(set, EnumSet::from([Fruit::Orange, Fruit::Grape]));
+    /// assert_eq!(EnumSet::from_bits(set.to_bits()), Some(set));
+    ///
+    /// assert_eq!(EnumSet::<{ Fruit::LENGTH }, Fruit>::from_bits(0b1000), None);
+    /// ```
+    pub const fn from_bits(bits: u128) -> Option<Self> {
+        let () = Self::ASSERT_BITS;
+
+        if LENGTH < u128::BITS as usize && bits >> LENGTH != 0 {
+            return None;
+        }
+
+        Some(Self::from_bits_truncate(bits))
+    }
+
+    /// Creates an `EnumSet` from a bitmask, where bit `i` represents the element with index `i`.
+    ///
+    /// Unlike [`EnumSet::from_bits`], bits outside of `0..LENGTH` are silently ignored.
+    /// Sets with more than 128 elements cannot be converted, which is a compile time error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumSet};
+    ///
+    /// let set = EnumSet::<{ Fruit::LENGTH }, Fruit>::from_bits_truncate(0b1111_0101);
+    /// assert_eq!(set, EnumSet::from([Fruit::Orange, Fruit::Grape]));
+    /// assert_eq!(set.to_bits(), 0b101);
+    /// ```
+    pub const fn from_bits_truncate(bits: u128) -> Self {
+        let () = Self::ASSERT_BITS;
+
+        let mut data = [None; LENGTH];
+        let mut i = 0;
+        while i < LENGTH {
+            if bits & (1 << i) != 0 {
+                data[i] = Some(());
+            }
+            i += 1;
+        }
+        Self(EnumMap::from_array(data))
+    }
+
+    /// Creates a set from the indices of its elements.
+    ///
+    /// Unlike the other constructors this is a `const fn` and can be used to create
+    /// sets in a const context. For enums implemented using the [`enumap`](crate::enumap) macro
+    /// the index of a variant is the variant cast to `usize`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of range, i.e. `index >= LENGTH`.
+    /// In a const context this results in a compile time error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumSet};
+    ///
+    /// const SWEET: EnumSet<{ Fruit::LENGTH }, Fruit> =
+    ///     EnumSet::from_indices(&[Fruit::Banana as usize, Fruit::Grape as usize]);
+    ///
+    /// assert_eq!(SWEET, EnumSet::from([Fruit::Banana, Fruit::Grape]));
+    /// ```
+    pub const fn from_indices(indices: &[usize]) -> Self {
+        let mut data = [None; LENGTH];
+        let mut i = 0;
+        while i < indices.len() {
+            data[indices[i]] = Some(());
+            i += 1;
+        }
+        Self(EnumMap::from_array(data))
+    }
+
+    /// Creates a set of all keys of `map` whose values satisfy the predicate `f`.
+    ///
+    /// The map is not consumed, keys are passed to `f` in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::{EnumMap, EnumSet};
+    ///
+    /// let stock = EnumMap::from([(Fruit::Orange, 12), (Fruit::Banana, 3), (Fruit::Apple, 7)]);
+    ///
+    /// let plenty = EnumSet::from_map_filter(&stock, |_, &count| count > 5);
+    /// assert_eq!(plenty, EnumSet::from([Fruit::Orange, Fruit::Apple]));
+    /// # assert_eq!(stock.len(), 3);
+    /// ```
+    pub fn from_map_filter<V, F: FnMut(E, &V) -> bool>(
+        map: &EnumMap<LENGTH, E, V>,
+        mut f: F,
+    ) -> Self {
+        map.iter()
+            .filter(|&(key, value)| f(key, value))
+            .map(|(key, _)| key)
+            .collect()
+    }
+
     /// Adds a value to the set.
     ///
     /// Returns whether the value was newly inserted. That is:
@@ -141,6 +345,44 @@ impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
         }
     }
 
+    /// Returns the number of values in the intersection
+    /// without visiting them, see [`EnumSet::intersection`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::{Enum, EnumSet};
+    ///
+    /// let a = EnumSet::from([Fruit::Orange, Fruit::Banana, Fruit::Apple]);
+    /// let b = EnumSet::from([Fruit::Orange, Fruit::Grape]);
+    ///
+    /// assert_eq!(a.intersection_count(&b), a.intersection(&b).count());
+    /// assert_eq!(a.intersection_count(&b), 1);
+    /// ```
+    pub fn intersection_count(&self, other: &EnumSet<LENGTH, E>) -> usize {
+        self.count_with(other, |a, b| a && b)
+    }
+
+    /// Retains only the values which are also yielded by the iterator,
+    /// i.e. intersects the set with the elements of the iterator in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::EnumSet;
+    ///
+    /// let mut set = EnumSet::from([Fruit::Orange, Fruit::Banana, Fruit::Apple]);
+    /// set.intersect_with_iter(vec![Fruit::Banana, Fruit::Grape, Fruit::Apple]);
+    ///
+    /// assert_eq!(set, EnumSet::from([Fruit::Banana, Fruit::Apple]));
+    /// ```
+    pub fn intersect_with_iter<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        let other: Self = iter.into_iter().collect();
+        *self = &*self & &other;
+    }
+
     /// Returns true if self has no elements in common with other.
     /// This is equivalent to checking for an empty intersection.
     ///
@@ -175,9 +417,27 @@ impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
     /// assert!(set.is_empty());
     /// set.insert(Fruit::Orange);
     /// assert!(!set.is_empty());
+    ///
+    /// const EMPTY: bool = EnumSet::<3, Fruit>::from_indices(&[]).is_empty();
+    /// assert!(EMPTY);
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the set contains every variant of the enum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumSet};
+    ///
+    /// assert!(EnumSet::<{ Fruit::LENGTH }, Fruit>::all().is_full());
+    /// assert!(!EnumSet::from([Fruit::Orange, Fruit::Grape]).is_full());
+    /// ```
+    pub const fn is_full(&self) -> bool {
+        self.len() == LENGTH
     }
 
     /// Returns true if the set is a subset of another, i.e.,
@@ -228,6 +488,9 @@ impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
 
     /// An iterator visiting all elements in order. The iterator element type is `E`.
     ///
+    /// The elements are counted once when the iterator is created,
+    /// which makes the iterator an [`ExactSizeIterator`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -242,14 +505,49 @@ impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
     /// for value in set.iter() {
     ///     println!("{value:?}");
     /// }
-    /// # let mut iter = set.iter();
+    ///
+    /// let mut iter = set.iter();
+    /// assert_eq!(iter.len(), 2);
     /// # assert!(matches!(iter.next(), Some(Fruit::Orange)));
+    /// # assert_eq!(iter.len(), 1);
     /// # assert!(matches!(iter.next(), Some(Fruit::Grape)));
+    /// # assert_eq!(iter.len(), 0);
     /// # assert!(iter.next().is_none());
+    /// # let mut iter = set.into_iter();
+    /// # iter.next();
+    /// # assert_eq!(iter.len(), 1);
     /// ```
     pub fn iter(&self) -> Iter<'_, LENGTH, E> {
         Iter {
             inner: self.0.keys(),
+            len: self.len(),
+        }
+    }
+
+    /// An iterator visiting the indices of all elements in order.
+    /// The iterator element type is `usize`.
+    ///
+    /// Unlike [`EnumSet::iter`] the elements are not reconstructed using [`Enum::from_index`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumSet};
+    ///
+    /// let set = EnumSet::from([Fruit::Orange, Fruit::Grape]);
+    ///
+    /// let indices: Vec<usize> = set.iter_indices().collect();
+    /// assert_eq!(indices, vec![0, 2]);
+    ///
+    /// for (index, fruit) in set.iter_indices().zip(set.iter()) {
+    ///     assert_eq!(index, Fruit::to_index(fruit));
+    /// }
+    /// ```
+    pub fn iter_indices(&self) -> Indices<'_, LENGTH, E> {
+        Indices {
+            inner: self.0.as_slice().iter().enumerate(),
+            _enum: PhantomData,
         }
     }
 
@@ -265,9 +563,20 @@ impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
     /// assert_eq!(set.len(), 0);
     /// set.insert(Fruit::Grape);
     /// assert_eq!(set.len(), 1);
+    ///
+    /// const LEN: usize = EnumSet::<3, Fruit>::from_indices(&[0, 2]).len();
+    /// assert_eq!(LEN, 2);
     /// ```
-    pub fn len(&self) -> usize {
-        self.0.len()
+    pub const fn len(&self) -> usize {
+        let mut len = 0;
+        let mut i = 0;
+        while i < LENGTH {
+            if self.0.as_slice()[i].is_some() {
+                len += 1;
+            }
+            i += 1;
+        }
+        len
     }
 
     /// Removes a value from the set. Returns whether the value was present in the set.
@@ -289,6 +598,156 @@ impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
         self.0.remove(value).is_some()
     }
 
+    /// Removes all values which are contained in `other`.
+    ///
+    /// Equivalent to replacing the set with `&set - other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::EnumSet;
+    ///
+    /// let mut set = EnumSet::from([Fruit::Orange, Fruit::Banana, Fruit::Apple]);
+    /// let other = EnumSet::from([Fruit::Banana, Fruit::Grape]);
+    ///
+    /// let expected = &set - &other;
+    /// set.remove_all(&other);
+    ///
+    /// assert_eq!(set, expected);
+    /// assert_eq!(set, EnumSet::from([Fruit::Orange, Fruit::Apple]));
+    /// ```
+    pub fn remove_all(&mut self, other: &EnumSet<LENGTH, E>) {
+        self.0.retain(|value, _| !other.contains(value));
+    }
+
+    /// Retains only the values which are also contained in `keep`.
+    ///
+    /// Equivalent to replacing the set with `&set & keep`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::EnumSet;
+    ///
+    /// let mut set = EnumSet::from([Fruit::Orange, Fruit::Banana, Fruit::Apple]);
+    /// let keep = EnumSet::from([Fruit::Banana, Fruit::Grape]);
+    ///
+    /// let expected = &set & &keep;
+    /// set.retain_all(&keep);
+    ///
+    /// assert_eq!(set, expected);
+    /// assert_eq!(set, EnumSet::from([Fruit::Banana]));
+    /// ```
+    pub fn retain_all(&mut self, keep: &EnumSet<LENGTH, E>) {
+        self.0.retain_keys(keep);
+    }
+
+    /// Creates a set containing a single value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{Enum, EnumSet};
+    ///
+    /// let set = EnumSet::single(Fruit::Banana);
+    ///
+    /// assert_eq!(set.len(), 1);
+    /// assert!(set.contains(Fruit::Banana));
+    /// ```
+    pub fn single(value: E) -> Self {
+        let mut set = Self::new();
+        set.insert(value);
+        set
+    }
+
+    /// An iterator visiting all subsets of the set, including the empty set and the set itself.
+    /// The iterator element type is `EnumSet<LENGTH, E>`.
+    ///
+    /// A set with `n` elements has `2^n` subsets, iterating all of them is exponential in the
+    /// size of the set. Like [`EnumSet::to_bits`] this is only available for sets with
+    /// up to 128 elements, which is checked at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumSet;
+    ///
+    /// let set = EnumSet::from([Fruit::Orange, Fruit::Grape]);
+    /// let subsets: Vec<_> = set.subsets().collect();
+    ///
+    /// assert_eq!(
+    ///     subsets,
+    ///     vec![
+    ///         EnumSet::new(),
+    ///         EnumSet::from([Fruit::Orange]),
+    ///         EnumSet::from([Fruit::Grape]),
+    ///         set,
+    ///     ]
+    /// );
+    /// ```
+    pub fn subsets(&self) -> Subsets<LENGTH, E> {
+        Subsets {
+            mask: self.to_bits(),
+            next: Some(0),
+            _enum: PhantomData,
+        }
+    }
+
+    /// Updates the set to be equal to `target`, returning the values which were
+    /// `(added, removed)` in the process.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::EnumSet;
+    ///
+    /// let mut actual = EnumSet::from([Fruit::Orange, Fruit::Banana]);
+    /// let desired = EnumSet::from([Fruit::Banana, Fruit::Grape, Fruit::Apple]);
+    ///
+    /// let (added, removed) = actual.sync_to(&desired);
+    ///
+    /// assert_eq!(actual, desired);
+    /// assert_eq!(added, EnumSet::from([Fruit::Grape, Fruit::Apple]));
+    /// assert_eq!(removed, EnumSet::from([Fruit::Orange]));
+    ///
+    /// let (added, removed) = actual.sync_to(&desired);
+    /// assert!(added.is_empty());
+    /// assert!(removed.is_empty());
+    /// ```
+    pub fn sync_to(
+        &mut self,
+        target: &EnumSet<LENGTH, E>,
+    ) -> (EnumSet<LENGTH, E>, EnumSet<LENGTH, E>) {
+        let added = target - self;
+        let removed = &*self - target;
+        *self = *target;
+        (added, removed)
+    }
+
+    /// Creates an [`EnumMap`] with a value for every element of the set.
+    ///
+    /// The value for each element is produced by calling `f` with the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{EnumMap, EnumSet};
+    ///
+    /// let set = EnumSet::from([Fruit::Orange, Fruit::Grape]);
+    /// let map = set.to_map_with(|fruit| fruit == Fruit::Orange);
+    ///
+    /// assert_eq!(map, EnumMap::from([(Fruit::Orange, true), (Fruit::Grape, false)]));
+    /// ```
+    pub fn to_map_with<V, F: FnMut(E) -> V>(&self, mut f: F) -> EnumMap<LENGTH, E, V> {
+        self.iter().map(|key| (key, f(key))).collect()
+    }
+
     /// Visits the values representing the union, i.e.,
     /// all the values in self or other, without duplicates.
     ///
@@ -318,6 +777,25 @@ impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
         }
     }
 
+    /// Returns the number of values in the union
+    /// without visiting them, see [`EnumSet::union`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::{Enum, EnumSet};
+    ///
+    /// let a = EnumSet::from([Fruit::Orange, Fruit::Banana, Fruit::Apple]);
+    /// let b = EnumSet::from([Fruit::Orange, Fruit::Grape]);
+    ///
+    /// assert_eq!(a.union_count(&b), a.union(&b).count());
+    /// assert_eq!(a.union_count(&b), 4);
+    /// ```
+    pub fn union_count(&self, other: &EnumSet<LENGTH, E>) -> usize {
+        self.count_with(other, |a, b| a || b)
+    }
+
     /// Visits the values representing the symmetric difference, i.e.,
     /// the values that are in self or in other but not in both.
     ///
@@ -351,6 +829,62 @@ impl<const LENGTH: usize, E: Enum<LENGTH>> EnumSet<LENGTH, E> {
             _enum: PhantomData,
         }
     }
+
+    /// Returns the number of values in the symmetric difference
+    /// without visiting them, see [`EnumSet::symmetric_difference`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, Apple } }
+    /// use enumap::{Enum, EnumSet};
+    ///
+    /// let a = EnumSet::from([Fruit::Orange, Fruit::Banana, Fruit::Apple]);
+    /// let b = EnumSet::from([Fruit::Orange, Fruit::Grape]);
+    ///
+    /// assert_eq!(a.symmetric_difference_count(&b), a.symmetric_difference(&b).count());
+    /// assert_eq!(a.symmetric_difference_count(&b), 3);
+    /// ```
+    pub fn symmetric_difference_count(&self, other: &EnumSet<LENGTH, E>) -> usize {
+        self.count_with(other, |a, b| a ^ b)
+    }
+
+    /// Returns the set as a bitmask, where bit `i` is set if the element with index `i` is present.
+    ///
+    /// Sets with more than 128 elements cannot be converted, which is a compile time error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::EnumSet;
+    ///
+    /// let set = EnumSet::from([Fruit::Orange, Fruit::Grape]);
+    /// assert_eq!(set.to_bits(), 0b101);
+    /// ```
+    pub const fn to_bits(&self) -> u128 {
+        let () = Self::ASSERT_BITS;
+
+        let data = self.0.as_slice();
+        let mut bits = 0;
+        let mut i = 0;
+        while i < LENGTH {
+            if data[i].is_some() {
+                bits |= 1 << i;
+            }
+            i += 1;
+        }
+        bits
+    }
+
+    fn count_with(&self, other: &EnumSet<LENGTH, E>, f: impl Fn(bool, bool) -> bool) -> usize {
+        self.0
+            .as_slice()
+            .iter()
+            .zip(other.0.as_slice())
+            .filter(|(a, b)| f(a.is_some(), b.is_some()))
+            .count()
+    }
 }
 
 impl<const LENGTH: usize, E: Enum<LENGTH>> Default for EnumSet<LENGTH, E> {
@@ -368,6 +902,129 @@ where
     }
 }
 
+/// Formats the set as its elements in order, separated by `, ` and enclosed in braces.
+///
+/// The output can be parsed back into a set using the [`FromStr`](core::str::FromStr) implementation.
+///
+/// # Examples
+///
+/// ```
+/// # enumap::enumap! { #[derive(Debug)] enum Fruit { Orange, Banana, Grape, } }
+/// # impl std::fmt::Display for Fruit {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         std::fmt::Debug::fmt(self, f)
+/// #     }
+/// # }
+/// use enumap::{Enum, EnumSet};
+///
+/// let set = EnumSet::from([Fruit::Grape, Fruit::Orange]);
+/// assert_eq!(set.to_string(), "{Orange, Grape}");
+///
+/// let set = EnumSet::<{ Fruit::LENGTH }, Fruit>::new();
+/// assert_eq!(set.to_string(), "{}");
+/// ```
+impl<const LENGTH: usize, E: Enum<LENGTH>> fmt::Display for EnumSet<LENGTH, E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        for (i, value) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Display::fmt(&value, f)?;
+        }
+        f.write_str("}")
+    }
+}
+
+/// Parses a comma separated list of elements into a set.
+///
+/// Whitespace around elements is ignored and the list may be enclosed in braces,
+/// which makes it the inverse of the [`Display`](fmt::Display) implementation.
+/// An empty string parses into an empty set.
+///
+/// # Examples
+///
+/// ```
+/// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+/// # impl std::fmt::Display for Fruit {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         std::fmt::Debug::fmt(self, f)
+/// #     }
+/// # }
+/// # impl std::str::FromStr for Fruit {
+/// #     type Err = String;
+/// #     fn from_str(s: &str) -> Result<Self, Self::Err> {
+/// #         match s {
+/// #             "Orange" => Ok(Self::Orange),
+/// #             "Banana" => Ok(Self::Banana),
+/// #             "Grape" => Ok(Self::Grape),
+/// #             _ => Err(format!("unknown fruit {s:?}")),
+/// #         }
+/// #     }
+/// # }
+/// use enumap::EnumSet;
+///
+/// let set: EnumSet<3, Fruit> = "Orange, Grape".parse().unwrap();
+/// assert_eq!(set, EnumSet::from([Fruit::Orange, Fruit::Grape]));
+///
+/// assert_eq!(set.to_string().parse(), Ok(set));
+/// assert_eq!("".parse(), Ok(EnumSet::<3, Fruit>::new()));
+/// assert_eq!(" { } ".parse(), Ok(EnumSet::<3, Fruit>::new()));
+///
+/// let err = "Orange,Kiwi".parse::<EnumSet<3, Fruit>>().unwrap_err();
+/// assert_eq!(err.to_string(), "invalid set element: unknown fruit \"Kiwi\"");
+/// ```
+impl<const LENGTH: usize, E: Enum<LENGTH>> core::str::FromStr for EnumSet<LENGTH, E>
+where
+    E: core::str::FromStr,
+{
+    type Err = ParseEnumSetError<E::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .unwrap_or(s)
+            .trim();
+
+        if s.is_empty() {
+            return Ok(Self::new());
+        }
+
+        s.split(',')
+            .map(|value| {
+                value
+                    .trim()
+                    .parse::<E>()
+                    .map_err(|error| ParseEnumSetError { error })
+            })
+            .collect()
+    }
+}
+
+/// Error returned when parsing an [`EnumSet`] from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseEnumSetError<Err> {
+    error: Err,
+}
+
+impl<Err> ParseEnumSetError<Err> {
+    /// Returns the error of the element which failed to parse.
+    pub fn into_inner(self) -> Err {
+        self.error
+    }
+}
+
+impl<Err: fmt::Display> fmt::Display for ParseEnumSetError<Err> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid set element: {}", self.error)
+    }
+}
+
 impl<const LENGTH: usize, E: Enum<LENGTH>, const N: usize> From<[E; N]> for EnumSet<LENGTH, E> {
     fn from(value: [E; N]) -> Self {
         Self::from_iter(value)
@@ -419,6 +1076,26 @@ impl<const LENGTH: usize, E: Enum<LENGTH>, V> From<&EnumMap<LENGTH, E, V>> for E
     }
 }
 
+impl<const LENGTH: usize, E: Enum<LENGTH>> From<EnumSet<LENGTH, E>> for EnumMap<LENGTH, E, ()> {
+    /// Converts an `EnumSet` into an `EnumMap` with a unit value for each element of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+    /// use enumap::{EnumMap, EnumSet};
+    ///
+    /// let set = EnumSet::from([Fruit::Banana, Fruit::Grape]);
+    /// let map = EnumMap::from(set);
+    ///
+    /// assert_eq!(map, EnumMap::from([(Fruit::Banana, ()), (Fruit::Grape, ())]));
+    /// assert_eq!(EnumSet::from(map), set);
+    /// ```
+    fn from(value: EnumSet<LENGTH, E>) -> Self {
+        value.0
+    }
+}
+
 impl<const LENGTH: usize, E: Enum<LENGTH>> FromIterator<E> for EnumSet<LENGTH, E> {
     fn from_iter<T: IntoIterator<Item = E>>(iter: T) -> Self {
         let mut set = Self::new();
@@ -435,6 +1112,27 @@ impl<'a, const LENGTH: usize, E: Enum<LENGTH>> FromIterator<&'a E> for EnumSet<L
     }
 }
 
+/// Collects the keys of key-value pairs into a set.
+///
+/// # Examples
+///
+/// ```
+/// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+/// use enumap::{EnumMap, EnumSet};
+///
+/// let map = EnumMap::from([(Fruit::Orange, 1), (Fruit::Grape, 3)]);
+///
+/// let set: EnumSet<3, _> = map.iter().collect();
+/// assert_eq!(set, EnumSet::from([Fruit::Orange, Fruit::Grape]));
+/// ```
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> FromIterator<(E, V)> for EnumSet<LENGTH, E> {
+    fn from_iter<T: IntoIterator<Item = (E, V)>>(iter: T) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
 impl<const LENGTH: usize, E: Enum<LENGTH>> Extend<E> for EnumSet<LENGTH, E> {
     fn extend<T: IntoIterator<Item = E>>(&mut self, iter: T) {
         for value in iter {
@@ -451,12 +1149,35 @@ impl<'a, const LENGTH: usize, E: Enum<LENGTH>> Extend<&'a E> for EnumSet<LENGTH,
     }
 }
 
+/// Inserts the keys of all key-value pairs from the iterator.
+///
+/// # Examples
+///
+/// ```
+/// # enumap::enumap! { #[derive(Debug, PartialEq)] enum Fruit { Orange, Banana, Grape, } }
+/// use enumap::{EnumMap, EnumSet};
+///
+/// let map = EnumMap::from([(Fruit::Banana, 2)]);
+///
+/// let mut set = EnumSet::from([Fruit::Orange]);
+/// set.extend(map.iter());
+/// assert_eq!(set, EnumSet::from([Fruit::Orange, Fruit::Banana]));
+/// ```
+impl<const LENGTH: usize, E: Enum<LENGTH>, V> Extend<(E, V)> for EnumSet<LENGTH, E> {
+    fn extend<T: IntoIterator<Item = (E, V)>>(&mut self, iter: T) {
+        for (key, _) in iter {
+            self.insert(key);
+        }
+    }
+}
+
 impl<const LENGTH: usize, E: Enum<LENGTH>> IntoIterator for EnumSet<LENGTH, E> {
     type Item = E;
     type IntoIter = IntoIter<LENGTH, E>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIter {
+            len: self.len(),
             inner: self.0.into_iter(),
         }
     }
@@ -650,29 +1371,122 @@ impl<const LENGTH: usize, E: Enum<LENGTH>> core::ops::Sub<&EnumSet<LENGTH, E>>
 /// Iterator returned from [`EnumSet::iter`].
 pub struct Iter<'a, const LENGTH: usize, E: Enum<LENGTH>> {
     inner: map::Keys<'a, LENGTH, E, ()>,
+    len: usize,
 }
 
 impl<'a, const LENGTH: usize, E: Enum<LENGTH>> Iterator for Iter<'a, LENGTH, E> {
     type Item = E;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next()
+        let value = self.inner.next()?;
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.inner.nth(n) {
+            Some(value) => {
+                self.len -= n + 1;
+                Some(value)
+            }
+            None => {
+                self.len = 0;
+                None
+            }
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.inner.last()
+    }
+
+    fn count(self) -> usize {
+        self.len
+    }
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>> ExactSizeIterator for Iter<'a, LENGTH, E> {}
+
+/// Iterator returned from [`EnumSet::iter_indices`].
+pub struct Indices<'a, const LENGTH: usize, E: Enum<LENGTH>> {
+    inner: core::iter::Enumerate<core::slice::Iter<'a, Option<()>>>,
+    _enum: PhantomData<E>,
+}
+
+impl<'a, const LENGTH: usize, E: Enum<LENGTH>> Iterator for Indices<'a, LENGTH, E> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.find_map(|(i, v)| v.map(|_| i))
+    }
+}
+
+/// Iterator returned from [`EnumSet::subsets`].
+pub struct Subsets<const LENGTH: usize, E: Enum<LENGTH>> {
+    mask: u128,
+    next: Option<u128>,
+    _enum: PhantomData<E>,
+}
+
+impl<const LENGTH: usize, E: Enum<LENGTH>> Iterator for Subsets<LENGTH, E> {
+    type Item = EnumSet<LENGTH, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        // Next submask of `mask` in ascending order, wraps around to `0` after the last one.
+        let next = current.wrapping_sub(self.mask) & self.mask;
+        self.next = (next != 0).then_some(next);
+        Some(EnumSet::from_bits_truncate(current))
     }
 }
 
 /// Iterator returned from [`EnumSet::into_iter`].
 pub struct IntoIter<const LENGTH: usize, E: Enum<LENGTH>> {
     inner: map::IntoIter<LENGTH, E, ()>,
+    len: usize,
 }
 
 impl<const LENGTH: usize, E: Enum<LENGTH>> Iterator for IntoIter<LENGTH, E> {
     type Item = E;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next().map(|(v, _)| v)
+        let (value, _) = self.inner.next()?;
+        self.len -= 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.inner.nth(n) {
+            Some((value, _)) => {
+                self.len -= n + 1;
+                Some(value)
+            }
+            None => {
+                self.len = 0;
+                None
+            }
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        self.inner.last().map(|(v, _)| v)
+    }
+
+    fn count(self) -> usize {
+        self.len
     }
 }
 
+impl<const LENGTH: usize, E: Enum<LENGTH>> ExactSizeIterator for IntoIter<LENGTH, E> {}
+
 /// Iterator returned from [`EnumSet::difference`].
 pub struct Difference<'a, const LENGTH: usize, E: Enum<LENGTH>> {
     this: &'a [Option<()>; LENGTH],